@@ -0,0 +1,403 @@
+use std::{cmp, collections::HashSet};
+
+use crate::{Cells, LifeGame, Rule, Value, DEAD, LIVE};
+
+const MARGIN: usize = 2;
+
+/// Final population above which a soup that merely stabilises is still kept.
+const SOUP_MIN_POPULATION: usize = 20;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fate {
+    Dies,
+    Stable,
+    Oscillates {
+        period: usize,
+    },
+    /// Did not settle within the step cap.
+    Unbounded,
+}
+
+/// The result of `LifeGame::step_detect_cycle`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepOutcome {
+    Changed,
+    /// The step changed nothing, as when `next` returns `None`.
+    Static,
+    /// The step returned the board to a state seen `period` steps ago.
+    Oscillating {
+        period: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OscillatorReport {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+    pub period: usize,
+}
+
+/// A soup from `search_soups` that produced a non-trivial result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SoupResult {
+    pub seed: u64,
+    pub fate: Fate,
+    pub generation: usize,
+    pub population: usize,
+}
+
+pub fn analyze_patterns(
+    patterns: &[(String, Vec<Vec<u8>>)],
+    max_steps: usize,
+) -> Vec<(String, Fate)> {
+    patterns
+        .iter()
+        .map(|(name, input)| {
+            (
+                name.clone(),
+                LifeGame::from(name, input).classify(max_steps),
+            )
+        })
+        .collect()
+}
+
+/// Evolves copies of `seed` under two rules for `steps` generations.
+pub fn compare_rules(seed: &LifeGame, a: Rule, b: Rule, steps: usize) -> (LifeGame, LifeGame) {
+    let evolve = |rule: Rule| {
+        let mut game = seed.clone();
+        game.set_rule(rule);
+        game.advance(steps);
+        game
+    };
+    (evolve(a), evolve(b))
+}
+
+/// Seeds `count` random boards with consecutive seeds from `seed_start`, runs
+/// each for up to `max_steps` generations, and keeps the soups that end in an
+/// oscillator, never settle, or leave more than a handful of live cells.
+pub fn search_soups(
+    width: usize,
+    height: usize,
+    density: f64,
+    seed_start: u64,
+    count: usize,
+    max_steps: usize,
+) -> Vec<SoupResult> {
+    let mut game = LifeGame::new(width, height);
+    (0..count as u64)
+        .filter_map(|offset| {
+            let seed = seed_start.wrapping_add(offset);
+            game.randomize(density, seed);
+            let mut soup = game.detached();
+            let fate = soup.run_to_fate(max_steps);
+            let population = soup.population();
+            let interesting = match fate {
+                Fate::Oscillates { .. } | Fate::Unbounded => true,
+                Fate::Stable | Fate::Dies => population > SOUP_MIN_POPULATION,
+            };
+            interesting.then_some(SoupResult {
+                seed,
+                fate,
+                generation: soup.generation,
+                population,
+            })
+        })
+        .collect()
+}
+
+impl LifeGame {
+    pub fn classify(&self, max_steps: usize) -> Fate {
+        self.detached().run_to_fate(max_steps)
+    }
+
+    /// Runs a copy of the board until it enters a cycle, then reports every
+    /// connected component that oscillates on its own with a period of 2 or more.
+    /// Still lifes, spaceships and components that only cycle together with their
+    /// neighbours are left out. Returns an empty list if no cycle is reached
+    /// within `max_steps`.
+    pub fn catalog_oscillators(&self, max_steps: usize) -> Vec<OscillatorReport> {
+        let mut game = self.detached();
+        if game.settle(max_steps).is_none() {
+            return Vec::new();
+        }
+
+        game.clusters()
+            .iter()
+            .filter_map(|cluster| {
+                let (x, y, width, height) = bounds(cluster);
+                let period = game.isolate(cluster).detect_period(max_steps)?;
+                (period > 1).then_some(OscillatorReport {
+                    x,
+                    y,
+                    width,
+                    height,
+                    period,
+                })
+            })
+            .collect()
+    }
+
+    /// Runs a copy of the board and returns the generation at which the
+    /// population first reaches zero, or `None` if it survives `max_steps`.
+    pub fn generations_to_extinction(&self, max_steps: usize) -> Option<usize> {
+        let mut game = self.detached();
+        for _ in 0..max_steps {
+            if game.population() == 0 {
+                return Some(game.generation);
+            }
+            game.next()?;
+        }
+        (game.population() == 0).then_some(game.generation)
+    }
+
+    /// Clears every connected component that is a still life on its own and
+    /// is not about to be disturbed by its surroundings, returning how many
+    /// cells were removed. Oscillators and moving objects are left intact.
+    pub fn remove_still_lifes(&mut self) -> usize {
+        let changing: HashSet<(usize, usize)> = self
+            .pending_changes()
+            .into_iter()
+            .map(|(x, y, _)| (x, y))
+            .collect();
+
+        let mut removed = 0;
+        for cluster in self.clusters() {
+            let undisturbed = cluster.iter().all(|cell| !changing.contains(cell));
+            if undisturbed && self.isolate(&cluster).next().is_none() {
+                for &(x, y) in &cluster {
+                    self.cells[y][x] = DEAD;
+                }
+                removed += cluster.len();
+            }
+        }
+        removed
+    }
+
+    /// Runs a copy of the board for up to `max_period` steps and returns the
+    /// number of steps after which it is back in its starting configuration,
+    /// with 1 for a still life. Returns `Some(0)` if a non-empty board dies
+    /// out first, and `None` if it neither repeats nor dies in time.
+    pub fn detect_period(&self, max_period: usize) -> Option<usize> {
+        let mut game = self.detached();
+        for step in 1..=max_period {
+            if game.next().is_none() {
+                return (step == 1).then_some(1);
+            }
+            if game.same_cells(self) {
+                return Some(step);
+            }
+            if game.population() == 0 {
+                return Some(0);
+            }
+        }
+        None
+    }
+
+    /// Steps like `next`, and also reports when the board comes back to one
+    /// of its last `max_period` states, comparing by a hash of the raw cells
+    /// so that decaying cells of Generations rules are told apart. Hashes are
+    /// remembered across calls and forgotten when the board is changed by
+    /// other means in between.
+    pub fn step_detect_cycle(&mut self, max_period: usize) -> StepOutcome {
+        let hash = self.hash_cells(|cell| cell);
+        if self.recent_hashes.back() != Some(&hash) {
+            self.recent_hashes.clear();
+            self.recent_hashes.push_back(hash);
+        }
+
+        if self.next().is_none() {
+            return StepOutcome::Static;
+        }
+
+        let hash = self.hash_cells(|cell| cell);
+        let period = self
+            .recent_hashes
+            .iter()
+            .rev()
+            .position(|&other| other == hash)
+            .map(|index| index + 1);
+        self.recent_hashes.push_back(hash);
+        while self.recent_hashes.len() > max_period {
+            self.recent_hashes.pop_front();
+        }
+
+        match period {
+            Some(period) => StepOutcome::Oscillating { period },
+            None => StepOutcome::Changed,
+        }
+    }
+
+    pub fn centroid(&self) -> Option<(f64, f64)> {
+        let (count, sum_x, sum_y) = self
+            .live_cells()
+            .fold((0, 0, 0), |(count, sum_x, sum_y), (x, y)| {
+                (count + 1, sum_x + x, sum_y + y)
+            });
+        (count > 0).then(|| (sum_x as f64 / count as f64, sum_y as f64 / count as f64))
+    }
+
+    /// Hashes the dimensions and live cells with FNV-1a, ignoring the name,
+    /// generation and settings. The result is stable across runs.
+    pub fn state_hash(&self) -> u64 {
+        self.hash_cells(|cell| (cell == LIVE) as u8)
+    }
+
+    /// Hashes the dimensions and every cell mapped through `byte` with FNV-1a.
+    fn hash_cells(&self, byte: impl Fn(Value) -> u8) -> u64 {
+        let mut hash = FNV_OFFSET;
+        let mut write = |byte: u8| {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        };
+
+        for byte in (self.width as u64)
+            .to_le_bytes()
+            .into_iter()
+            .chain((self.height as u64).to_le_bytes())
+        {
+            write(byte);
+        }
+        for &cell in self.cells.iter().flatten() {
+            write(byte(cell));
+        }
+        hash
+    }
+
+    fn run_to_fate(&mut self, max_steps: usize) -> Fate {
+        match self.settle(max_steps) {
+            None => Fate::Unbounded,
+            Some(_) if self.live_cells().next().is_none() => Fate::Dies,
+            Some(1) => Fate::Stable,
+            Some(period) => Fate::Oscillates { period },
+        }
+    }
+
+    fn settle(&mut self, max_steps: usize) -> Option<usize> {
+        let mut history = vec![(self.state_hash(), self.cells.clone())];
+        for _ in 0..max_steps {
+            if self.next().is_none() {
+                return Some(1);
+            }
+            let hash = self.state_hash();
+            if let Some(index) = history
+                .iter()
+                .position(|(other, cells)| *other == hash && *cells == self.cells)
+            {
+                return Some(history.len() - index);
+            }
+            history.push((hash, self.cells.clone()));
+        }
+        None
+    }
+
+    fn clusters(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut visited = vec![vec![false; self.width]; self.height];
+        let mut clusters = Vec::new();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if visited[y][x] || self.cells[y][x] != LIVE {
+                    continue;
+                }
+
+                visited[y][x] = true;
+                let mut stack = vec![(x, y)];
+                let mut cluster = Vec::new();
+
+                while let Some((x, y)) = stack.pop() {
+                    cluster.push((x, y));
+                    for (nx, ny) in self.neighbors(x, y) {
+                        if !visited[ny][nx] && self.cells[ny][nx] == LIVE {
+                            visited[ny][nx] = true;
+                            stack.push((nx, ny));
+                        }
+                    }
+                }
+
+                clusters.push(cluster);
+            }
+        }
+
+        clusters
+    }
+
+    fn isolate(&self, cluster: &[(usize, usize)]) -> LifeGame {
+        let (x, y, width, height) = bounds(cluster);
+        let left = x.saturating_sub(MARGIN);
+        let top = y.saturating_sub(MARGIN);
+        let right = cmp::min(x + width + MARGIN, self.width);
+        let bottom = cmp::min(y + height + MARGIN, self.height);
+
+        let mut cells: Cells = vec![vec![DEAD; right - left]; bottom - top];
+        for &(x, y) in cluster {
+            cells[y - top][x - left] = LIVE;
+        }
+        self.with_cells(cells)
+    }
+}
+
+fn bounds(cluster: &[(usize, usize)]) -> (usize, usize, usize, usize) {
+    let left = cluster.iter().map(|&(x, _)| x).min().unwrap_or(0);
+    let top = cluster.iter().map(|&(_, y)| y).min().unwrap_or(0);
+    let right = cluster.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+    let bottom = cluster.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+    (left, top, right - left, bottom - top)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn centroid_of_symmetric_pattern_is_its_center() {
+        let mut game = LifeGame::new(7, 5);
+        game.set_alives(&[(2, 1), (4, 1), (3, 2), (2, 3), (4, 3)]);
+        assert_eq!(game.centroid(), Some((3.0, 2.0)));
+        assert_eq!(LifeGame::new(3, 3).centroid(), None);
+    }
+
+    #[test]
+    fn counts_generations_to_extinction() {
+        let mut game = LifeGame::new(5, 5);
+        game.set_alives(&[(1, 1), (2, 2), (3, 3)]);
+        assert_eq!(game.generations_to_extinction(10), Some(2));
+
+        let mut block = LifeGame::new(4, 4);
+        block.set_alives(&[(1, 1), (2, 1), (1, 2), (2, 2)]);
+        assert_eq!(block.generations_to_extinction(10), None);
+    }
+
+    #[test]
+    fn decaying_cells_are_not_a_cycle() {
+        let mut game = LifeGame::new(3, 3).with_rule(Rule::parse("B2/S").unwrap());
+        game.set_states(3);
+        game.cells[1][1] = 2;
+        assert_eq!(game.step_detect_cycle(16), StepOutcome::Changed);
+        assert_eq!(game.step_detect_cycle(16), StepOutcome::Static);
+    }
+
+    #[test]
+    fn reported_periods_match_generations_boards() {
+        for seed in 0..5 {
+            let mut game =
+                LifeGame::random(12, 12, 0.3, seed).with_rule(Rule::parse("B2/S").unwrap());
+            game.set_states(3);
+            let mut boards = vec![game.cells.clone()];
+            for _ in 0..40 {
+                let outcome = game.step_detect_cycle(16);
+                if let StepOutcome::Oscillating { period } = outcome {
+                    assert_eq!(game.cells, boards[boards.len() - period], "seed {}", seed);
+                }
+                if outcome == StepOutcome::Static {
+                    break;
+                }
+                boards.push(game.cells.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::{fs::File, io, path::Path, time::Duration};
+
+use gif::{Encoder, EncodingError, Frame, Repeat};
+
+use crate::LifeGame;
+
+impl LifeGame {
+    /// Writes up to `frames` generations as a looping animated GIF, see
+    /// `collect_frames` and `render_rgba`. Each frame is shown for `delay`,
+    /// rounded to the GIF resolution of 10ms.
+    pub fn save_gif<P: AsRef<Path>>(
+        &self,
+        path: P,
+        frames: usize,
+        cell_size: usize,
+        live: [u8; 4],
+        dead: [u8; 4],
+        delay: Duration,
+    ) -> Result<(), EncodingError> {
+        let (Ok(width), Ok(height)) = (
+            u16::try_from(self.width * cell_size),
+            u16::try_from(self.height * cell_size),
+        ) else {
+            return Err(
+                io::Error::new(io::ErrorKind::InvalidInput, "board too large for a GIF").into(),
+            );
+        };
+        let delay = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+
+        let mut encoder = Encoder::new(File::create(path)?, width, height, &[])?;
+        encoder.set_repeat(Repeat::Infinite)?;
+        for game in self.collect_frames(frames) {
+            let mut pixels = game.render_rgba(cell_size, live, dead);
+            let mut frame = Frame::from_rgba_speed(width, height, &mut pixels, 10);
+            frame.delay = delay;
+            encoder.write_frame(&frame)?;
+        }
+        Ok(())
+    }
+}
@@ -1,24 +1,35 @@
 use std::{
+    collections::{HashMap, HashSet},
+    env,
     error::Error,
-    time::{Duration, Instant},
+    fs,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
 use ratatui::{
-    layout::Rect,
+    layout::{Rect, Size},
     style::{Color, Style, Stylize},
     text::Text,
-    widgets::Block,
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
     DefaultTerminal, Frame,
 };
-use rust_life_game::LifeGame;
+use rust_life_game::{EdgeMode, LifeGame, Neighborhood, StepOutcome, Symmetry};
+
+use keys::{Action, EDIT_BINDINGS, RUN_BINDINGS};
+
+mod keys;
 
 struct Setting {
-    x: u16,
-    y: u16,
+    x: i32,
+    y: i32,
     size: u16,
-    color: u8,
+    color: Color,
+    truecolor: bool,
     tick_rate: Duration,
+    auto_fit: bool,
+    duplicate_offset: usize,
+    brush_size: usize,
 }
 
 impl Setting {
@@ -27,8 +38,13 @@ impl Setting {
             x: 0,
             y: 0,
             size: 1,
-            color: 0,
-            tick_rate: Duration::from_secs(1),
+            color: Color::Indexed(0),
+            truecolor: env::var("COLORTERM")
+                .is_ok_and(|term| term.contains("truecolor") || term.contains("24bit")),
+            tick_rate: TICK_RATE,
+            auto_fit: true,
+            duplicate_offset: 0,
+            brush_size: 1,
         }
     }
 
@@ -38,23 +54,188 @@ impl Setting {
         }
     }
 
-    fn move_x(&mut self, x: i16) {
-        if let Some(x) = self.x.checked_add_signed(x) {
-            self.x = x.clamp(0, 100);
+    fn faster(&mut self) {
+        self.tick_rate = (self.tick_rate / 2).max(MIN_TICK_RATE);
+    }
+
+    fn slower(&mut self) {
+        self.tick_rate = (self.tick_rate * 2).min(MAX_TICK_RATE);
+    }
+
+    fn fit(&mut self, area: Size, width: u16, height: u16) {
+        if !self.auto_fit || width == 0 || height == 0 {
+            return;
         }
+        let fit_width = area.width as u32 * 4 / 5 / (width as u32 * 2);
+        let fit_height = (area.height as u32 * 4 / 5).saturating_sub(1) / height as u32;
+        self.size = fit_width.min(fit_height).clamp(1, 10) as u16;
+    }
+
+    fn toggle_auto_fit(&mut self) {
+        self.auto_fit = !self.auto_fit;
+    }
+
+    fn shift_duplicate(&mut self, delta: isize) {
+        self.duplicate_offset = self.duplicate_offset.saturating_add_signed(delta).min(100);
+    }
+
+    fn add_brush_size(&mut self, delta: isize) {
+        self.brush_size = self.brush_size.saturating_add_signed(delta).clamp(1, 9);
+    }
+
+    fn move_x(&mut self, x: i32) {
+        self.x = self.x.saturating_add(x).min(100);
     }
 
-    fn move_y(&mut self, y: i16) {
-        if let Some(y) = self.y.checked_add_signed(y) {
-            self.y = y.clamp(0, 100);
+    fn move_y(&mut self, y: i32) {
+        self.y = self.y.saturating_add(y).min(100);
+    }
+
+    fn follow(&mut self, area: Size, (x, y): (f64, f64)) {
+        let width = area.width as i32;
+        let height = area.height as i32;
+        let center_x = (x * (self.size * 2) as f64) as i32 + self.size as i32;
+        let center_y = (y * self.size as f64) as i32 + self.size as i32 / 2 + 1;
+
+        if !(width / 4..=width * 3 / 4).contains(&(self.x + center_x)) {
+            self.x = width / 2 - center_x;
+        }
+        if !(height / 4..=height * 3 / 4).contains(&(self.y + center_y)) {
+            self.y = height / 2 - center_y;
         }
     }
 
     fn next_color(&mut self) {
-        self.color = (self.color + 1) % 16;
+        self.color = match self.color {
+            Color::Indexed(color) => Color::Indexed((color + 1) % 16),
+            _ => Color::Indexed(0),
+        };
+    }
+
+    fn nudge_color(&mut self, channel: usize) {
+        let mut rgb = match self.color {
+            Color::Rgb(r, g, b) => [r, g, b],
+            _ => [0x80; 3],
+        };
+        rgb[channel] = rgb[channel].wrapping_add(0x20);
+        self.color = Color::Rgb(rgb[0], rgb[1], rgb[2]);
+    }
+
+    fn color(&self) -> Color {
+        match self.color {
+            Color::Rgb(r, g, b) if !self.truecolor => {
+                let level = |c: u8| c as u16 * 5 / 255;
+                Color::Indexed((16 + 36 * level(r) + 6 * level(g) + level(b)) as u8)
+            }
+            color => color,
+        }
+    }
+}
+
+pub const TICK_RATE: Duration = Duration::from_secs(1);
+
+const MIN_TICK_RATE: Duration = Duration::from_millis(50);
+
+const MAX_TICK_RATE: Duration = Duration::from_secs(2);
+
+const DENSITIES: [f64; 4] = [0.1, 0.25, 0.375, 0.5];
+
+const CYCLE_LIMIT: usize = 16;
+
+const HISTORY_LIMIT: usize = 256;
+
+const HISTORY_BYTES: usize = 64 << 20;
+
+const SOUP_MIN_SIZE: usize = 8;
+
+const MESSAGE_DURATION: Duration = Duration::from_secs(2);
+
+const COUNTS_MIN_SIZE: u16 = 2;
+
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+#[cfg(feature = "image")]
+const EXPORT_CELL_SIZE: usize = 8;
+
+#[cfg(feature = "image")]
+const ANSI: [[u8; 3]; 16] = [
+    [0, 0, 0],
+    [205, 0, 0],
+    [0, 205, 0],
+    [205, 205, 0],
+    [0, 0, 238],
+    [205, 0, 205],
+    [0, 205, 205],
+    [229, 229, 229],
+    [127, 127, 127],
+    [255, 0, 0],
+    [0, 255, 0],
+    [255, 255, 0],
+    [92, 92, 255],
+    [255, 0, 255],
+    [0, 255, 255],
+    [255, 255, 255],
+];
+
+#[cfg(feature = "image")]
+fn rgba(color: Color) -> [u8; 4] {
+    let index = match color {
+        Color::Rgb(r, g, b) => return [r, g, b, 255],
+        Color::Indexed(index) => index,
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White | Color::Reset => 15,
+    };
+    let [r, g, b] = match index {
+        0..=15 => ANSI[index as usize],
+        16..=231 => {
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            let index = index - 16;
+            [level(index / 36), level(index / 6 % 6), level(index % 6)]
+        }
+        _ => [8 + (index - 232) * 10; 3],
+    };
+    [r, g, b, 255]
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Run,
+    Edit,
+}
+
+fn place(area: Rect, x: i32, y: i32, width: i32, height: i32) -> Rect {
+    let left = x.clamp(0, area.width as i32);
+    let top = y.clamp(0, area.height as i32);
+    let right = x.saturating_add(width).clamp(0, area.width as i32);
+    let bottom = y.saturating_add(height).clamp(0, area.height as i32);
+    Rect {
+        x: area.x + left as u16,
+        y: area.y + top as u16,
+        width: (right - left) as u16,
+        height: (bottom - top) as u16,
     }
 }
 
+enum Tick {
+    Idle,
+    Stepped,
+    Stable,
+}
+
 enum HandleResult {
     Quit,
     Next,
@@ -63,35 +244,97 @@ enum HandleResult {
 
 pub struct App<'a> {
     setting: Setting,
-    inputs: &'a Vec<(String, Vec<Vec<u8>>)>,
+    patterns: &'a [LifeGame],
+    skip: usize,
+    screensaver: bool,
     last_tick: Instant,
     life_game: LifeGame,
+    density_index: usize,
+    density: Option<f64>,
+    seed: u64,
+    explain: bool,
+    changes: HashMap<(usize, usize), bool>,
+    counts: bool,
+    counting: bool,
+    mode: Mode,
+    cursor: (usize, usize),
+    follow: bool,
+    message: Option<(String, Instant)>,
+    help: bool,
+    overview: bool,
+    paused: bool,
+    breakpoint: Option<usize>,
+    prompt: Option<String>,
 }
 
 impl<'a> App<'a> {
-    pub fn new(inputs: &'a Vec<(String, Vec<Vec<u8>>)>) -> Self {
+    pub fn new(patterns: &'a [LifeGame], skip: usize, screensaver: bool) -> Self {
         App {
             setting: Setting::new(),
-            inputs,
+            patterns,
+            skip,
+            screensaver,
             last_tick: Instant::now(),
             life_game: LifeGame::new(0, 0),
+            density_index: 0,
+            density: None,
+            seed: 0,
+            explain: false,
+            changes: HashMap::new(),
+            counts: false,
+            counting: false,
+            mode: Mode::Run,
+            cursor: (0, 0),
+            follow: false,
+            message: None,
+            help: false,
+            overview: false,
+            paused: false,
+            breakpoint: None,
+            prompt: None,
         }
     }
 
     pub fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<(), Box<dyn Error>> {
         self.last_tick = Instant::now();
+        if self.screensaver {
+            self.seed = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_nanos() as u64);
+        }
 
-        for (name, input) in self.inputs.iter().cycle() {
-            self.life_game = LifeGame::from(name, input);
+        for pattern in self.patterns.iter().cycle() {
+            self.density = None;
+            self.life_game = if self.screensaver {
+                self.pick(terminal.size()?)
+            } else {
+                pattern.clone()
+            };
+            self.life_game.set_history_limit(self.history_limit());
+            self.life_game.advance(self.skip);
+            self.fit(terminal.size()?);
 
             loop {
+                if self.follow {
+                    if let Some(centroid) = self.life_game.centroid() {
+                        self.setting.follow(terminal.size()?, centroid);
+                    }
+                }
+
                 terminal.draw(|frame| self.draw(frame))?;
 
-                let timeout = self
+                let mut timeout = self
                     .setting
                     .tick_rate
                     .saturating_sub(self.last_tick.elapsed());
 
+                if !self.changes.is_empty() {
+                    timeout = timeout.min(
+                        self.explain_duration()
+                            .saturating_sub(self.last_tick.elapsed()),
+                    );
+                }
+
                 if event::poll(timeout)? {
                     if let Event::Key(key) = event::read()? {
                         match self.handle_key_event(key) {
@@ -102,13 +345,7 @@ impl<'a> App<'a> {
                     }
                 }
 
-                if self.last_tick.elapsed() < self.setting.tick_rate {
-                    continue;
-                }
-
-                self.last_tick = Instant::now();
-
-                if let None = self.life_game.next() {
+                if let Tick::Stable = self.on_tick(Instant::now()) {
                     break;
                 }
             }
@@ -117,71 +354,679 @@ impl<'a> App<'a> {
         Ok(())
     }
 
+    /// Chooses the screensaver's next board: one of the patterns or, one time
+    /// in `patterns.len() + 1`, a random soup sized to the terminal.
+    fn pick(&mut self, area: Size) -> LifeGame {
+        self.seed = self.seed.wrapping_add(1);
+        let choice = (self.seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 32) as usize;
+        match self.patterns.get(choice % (self.patterns.len() + 1)) {
+            Some(pattern) => pattern.clone(),
+            None => {
+                let density = DENSITIES[choice % DENSITIES.len()];
+                self.density = Some(density);
+                LifeGame::random(
+                    (area.width as usize / 4).max(SOUP_MIN_SIZE),
+                    (area.height as usize / 2).max(SOUP_MIN_SIZE),
+                    density,
+                    self.seed,
+                )
+            }
+        }
+    }
+
+    fn on_tick(&mut self, now: Instant) -> Tick {
+        let elapsed = now.saturating_duration_since(self.last_tick);
+
+        if elapsed >= self.explain_duration() {
+            self.changes.clear();
+        }
+
+        if self.mode == Mode::Edit || self.help || self.paused {
+            self.last_tick = now;
+            return Tick::Idle;
+        }
+
+        if elapsed < self.setting.tick_rate {
+            return Tick::Idle;
+        }
+
+        self.last_tick = now;
+
+        if self.counts && !self.counting {
+            self.counting = true;
+            return Tick::Idle;
+        }
+        self.counting = false;
+
+        self.step()
+    }
+
+    fn step(&mut self) -> Tick {
+        let changes = self.explain.then(|| self.life_game.pending_changes());
+
+        if self.life_game.step_detect_cycle(CYCLE_LIMIT) != StepOutcome::Changed {
+            return Tick::Stable;
+        }
+
+        if let Some(changes) = changes {
+            self.changes = changes
+                .into_iter()
+                .map(|(x, y, alive)| ((x, y), alive))
+                .collect();
+        }
+
+        if self.breakpoint == Some(self.life_game.generation()) {
+            self.breakpoint = None;
+            self.paused = true;
+            self.notify(format!("paused at gen {}", self.life_game.generation()));
+        }
+
+        Tick::Stepped
+    }
+
+    fn set_breakpoint(&mut self) {
+        let Some(input) = self.prompt.take() else {
+            return;
+        };
+        self.breakpoint = input.parse().ok();
+        match self.breakpoint {
+            Some(generation) => self.notify(format!("break at gen {}", generation)),
+            None => self.notify("breakpoint cleared".into()),
+        }
+    }
+
+    fn explain_duration(&self) -> Duration {
+        self.setting.tick_rate / 2
+    }
+
+    fn toggle_explain(&mut self) {
+        self.explain = !self.explain;
+        self.changes.clear();
+    }
+
+    fn toggle_counts(&mut self) {
+        self.counts = !self.counts;
+        self.counting = false;
+        if self.counts && self.setting.size < COUNTS_MIN_SIZE {
+            self.notify(format!(
+                "zoom to {} to see neighbor counts",
+                COUNTS_MIN_SIZE
+            ));
+        }
+    }
+
+    fn toggle_edge_mode(&mut self) {
+        let edge_mode = match self.life_game.edge_mode() {
+            EdgeMode::Bounded => EdgeMode::Toroidal,
+            EdgeMode::Toroidal => EdgeMode::HorizontalCylinder,
+            EdgeMode::HorizontalCylinder => EdgeMode::VerticalCylinder,
+            EdgeMode::VerticalCylinder => EdgeMode::LiveBorder,
+            EdgeMode::LiveBorder => EdgeMode::Bounded,
+        };
+        self.life_game.set_edge_mode(edge_mode);
+    }
+
+    fn toggle_neighborhood(&mut self) {
+        let neighborhood = match self.life_game.neighborhood() {
+            Neighborhood::Moore => Neighborhood::VonNeumann,
+            Neighborhood::VonNeumann => Neighborhood::Hex,
+            Neighborhood::Hex => Neighborhood::Moore,
+        };
+        self.life_game.set_neighborhood(neighborhood);
+    }
+
+    fn notify(&mut self, message: String) {
+        self.message = Some((message, Instant::now()));
+    }
+
+    fn duplicate(&mut self) {
+        let mut copy = self.life_game.clone();
+        copy.flip_horizontal();
+        self.life_game
+            .stamp(&copy, self.setting.duplicate_offset, 0);
+        self.notify(format!(
+            "duplicated mirror at +{}",
+            self.setting.duplicate_offset
+        ));
+    }
+
+    fn export_path(&self, extension: &str) -> String {
+        let game = &self.life_game;
+        let name = match game.name() {
+            "" => "board".to_string(),
+            name => name.to_lowercase(),
+        };
+        format!("{}-{}.{}", name, game.generation(), extension)
+    }
+
+    fn save_rle(&mut self) {
+        let path = self.export_path("rle");
+        match fs::write(&path, self.life_game.to_rle()) {
+            Ok(()) => self.notify(format!("saved {}", path)),
+            Err(error) => self.notify(format!("save failed: {}", error)),
+        }
+    }
+
+    #[cfg(feature = "image")]
+    fn export_png(&mut self) {
+        let path = self.export_path("png");
+        let game = &self.life_game;
+        let result = game.save_png(
+            &path,
+            EXPORT_CELL_SIZE,
+            rgba(self.setting.color()),
+            rgba(Color::White),
+        );
+        match result {
+            Ok(()) => self.notify(format!("saved {}", path)),
+            Err(error) => self.notify(format!("export failed: {}", error)),
+        }
+    }
+
+    /// Keeps up to `HISTORY_LIMIT` steps, fewer on boards so large that they
+    /// would take more than `HISTORY_BYTES`.
+    fn history_limit(&self) -> usize {
+        let cells = self.life_game.width() as usize * self.life_game.height() as usize;
+        (HISTORY_BYTES / cells.max(1)).clamp(1, HISTORY_LIMIT)
+    }
+
+    fn fit(&mut self, area: Size) {
+        let game = &self.life_game;
+        self.setting.fit(area, game.width(), game.height());
+    }
+
+    fn reseed(&mut self) {
+        let density = DENSITIES[self.density_index];
+        self.density_index = (self.density_index + 1) % DENSITIES.len();
+        self.seed = self.seed.wrapping_add(1);
+        self.life_game.randomize(density, self.seed);
+        self.density = Some(density);
+    }
+
+    fn move_cursor(&mut self, dx: isize, dy: isize) {
+        let (x, y) = self.cursor;
+        let width = self.life_game.width() as usize;
+        let height = self.life_game.height() as usize;
+        self.cursor = (
+            x.saturating_add_signed(dx).min(width.saturating_sub(1)),
+            y.saturating_add_signed(dy).min(height.saturating_sub(1)),
+        );
+    }
+
+    fn brush(&self) -> Vec<(usize, usize)> {
+        let game = &self.life_game;
+        let size = self.setting.brush_size as isize;
+        let (x, y) = (self.cursor.0 as isize, self.cursor.1 as isize);
+        let (left, top) = (x - (size - 1) / 2, y - (size - 1) / 2);
+        let xs = (left.max(0)..(left + size).min(game.width() as isize)).map(|x| x as usize);
+        let ys = (top.max(0)..(top + size).min(game.height() as isize)).map(|y| y as usize);
+        ys.flat_map(|y| xs.clone().map(move |x| (x, y))).collect()
+    }
+
+    /// Fills the brush when the cell under the cursor is dead and clears it
+    /// otherwise.
+    fn paint(&mut self) {
+        let (x, y) = self.cursor;
+        let alive = !self.life_game.live_at(x as isize, y as isize);
+        for (x, y) in self.brush() {
+            self.life_game.set(x, y, alive);
+        }
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> HandleResult {
         if key.kind != KeyEventKind::Release {
-            match key.code {
-                KeyCode::Char('q') => return HandleResult::Quit,
-                KeyCode::Char('n') => {
-                    self.last_tick = Instant::now();
-                    return HandleResult::Next;
+            if self.help {
+                match key.code {
+                    KeyCode::Char('?') | KeyCode::Esc => self.help = false,
+                    KeyCode::Char('q') => return HandleResult::Quit,
+                    _ => {}
                 }
-                KeyCode::Char('+') => self.setting.add_size(1),
-                KeyCode::Char('-') => self.setting.add_size(-1),
-                KeyCode::Char('c') => self.setting.next_color(),
-                KeyCode::Right | KeyCode::Char('l') => self.setting.move_x(1),
-                KeyCode::Left | KeyCode::Char('h') => self.setting.move_x(-1),
-                KeyCode::Down | KeyCode::Char('j') => self.setting.move_y(1),
-                KeyCode::Up | KeyCode::Char('k') => self.setting.move_y(-1),
-                KeyCode::Char(' ') => {
-                    if let Some(last_tick) = self.last_tick.checked_sub(self.setting.tick_rate) {
-                        self.last_tick = last_tick
+                return HandleResult::Keep;
+            }
+
+            if let Some(input) = self.prompt.as_mut() {
+                match key.code {
+                    KeyCode::Char(digit) if digit.is_ascii_digit() => input.push(digit),
+                    KeyCode::Backspace => {
+                        input.pop();
                     }
+                    KeyCode::Enter => self.set_breakpoint(),
+                    KeyCode::Esc => self.prompt = None,
+                    _ => {}
+                }
+                return HandleResult::Keep;
+            }
+
+            let action = match self.mode {
+                Mode::Edit => keys::find(EDIT_BINDINGS, key.code),
+                Mode::Run => None,
+            };
+            if let Some(action) = action.or_else(|| keys::find(RUN_BINDINGS, key.code)) {
+                return self.perform(action);
+            }
+        }
+        HandleResult::Keep
+    }
+
+    fn perform(&mut self, action: Action) -> HandleResult {
+        match action {
+            Action::Quit => return HandleResult::Quit,
+            Action::NextPattern => {
+                self.last_tick = Instant::now();
+                return HandleResult::Next;
+            }
+            Action::Restart => {
+                self.life_game.reset();
+                self.changes.clear();
+            }
+            Action::Faster => self.setting.faster(),
+            Action::Slower => self.setting.slower(),
+            Action::ZoomIn => self.setting.add_size(1),
+            Action::ZoomOut => self.setting.add_size(-1),
+            Action::NextColor => self.setting.next_color(),
+            Action::NudgeRed => self.setting.nudge_color(0),
+            Action::NudgeGreen => self.setting.nudge_color(1),
+            Action::NudgeBlue => self.setting.nudge_color(2),
+            Action::AutoFit => self.setting.toggle_auto_fit(),
+            Action::Invert => self.life_game.invert(),
+            Action::Symmetrize => self.life_game.make_symmetric(Symmetry::FourFold),
+            Action::LaunchGlider => {
+                self.seed = self.seed.wrapping_add(1);
+                self.life_game.launch_glider(self.seed);
+            }
+            Action::Reseed => self.reseed(),
+            Action::Explain => self.toggle_explain(),
+            Action::NeighborCounts => self.toggle_counts(),
+            Action::EdgeMode => self.toggle_edge_mode(),
+            Action::Neighborhood => self.toggle_neighborhood(),
+            Action::Follow => self.follow = !self.follow,
+            Action::Duplicate => self.duplicate(),
+            Action::DuplicateCloser => self.setting.shift_duplicate(-1),
+            Action::DuplicateFarther => self.setting.shift_duplicate(1),
+            Action::SaveRle => self.save_rle(),
+            #[cfg(feature = "image")]
+            Action::ExportPng => self.export_png(),
+            #[cfg(not(feature = "image"))]
+            Action::ExportPng => self.notify("built without the image feature".into()),
+            Action::Edit if self.mode == Mode::Edit => self.mode = Mode::Run,
+            Action::Edit => {
+                self.mode = Mode::Edit;
+                self.move_cursor(0, 0);
+            }
+            Action::MoveRight => self.setting.move_x(1),
+            Action::MoveLeft => self.setting.move_x(-1),
+            Action::MoveDown => self.setting.move_y(1),
+            Action::MoveUp => self.setting.move_y(-1),
+            Action::Step if self.paused => {
+                self.step();
+            }
+            Action::Step => {
+                if let Some(last_tick) = self.last_tick.checked_sub(self.setting.tick_rate) {
+                    self.last_tick = last_tick
                 }
-                _ => {}
             }
+            Action::Back => {
+                self.paused = true;
+                self.changes.clear();
+                if self.life_game.prev().is_none() {
+                    self.notify("no earlier generation".into());
+                }
+            }
+            Action::Pause => self.paused = !self.paused,
+            Action::Breakpoint => self.prompt = Some(String::new()),
+            Action::Help => self.help = true,
+            Action::Overview => self.overview = !self.overview,
+            Action::CursorRight => self.move_cursor(1, 0),
+            Action::CursorLeft => self.move_cursor(-1, 0),
+            Action::CursorDown => self.move_cursor(0, 1),
+            Action::CursorUp => self.move_cursor(0, -1),
+            Action::ToggleCell => self.paint(),
+            Action::BrushSmaller => self.setting.add_brush_size(-1),
+            Action::BrushLarger => self.setting.add_brush_size(1),
+            Action::Run => self.mode = Mode::Run,
         }
         HandleResult::Keep
     }
 
     fn draw(&self, frame: &mut Frame) {
+        let area = frame.area();
+
+        if self.overview {
+            self.draw_overview(frame);
+        } else {
+            self.draw_board(frame);
+        }
+
+        if self.help {
+            let lines = keys::help_lines();
+            let width = lines.iter().map(|line| line.len()).max().unwrap_or(0) as i32 + 4;
+            let height = lines.len() as i32 + 2;
+            let help = place(
+                area,
+                (area.width as i32 - width) / 2,
+                (area.height as i32 - height) / 2,
+                width,
+                height,
+            );
+            frame.render_widget(Clear, help);
+            frame.render_widget(
+                Paragraph::new(Text::from(lines.join("\n"))).block(
+                    Block::bordered()
+                        .title(" help ")
+                        .padding(Padding::horizontal(1)),
+                ),
+                help,
+            );
+        }
+
+        let status = self.status();
+        frame.render_widget(
+            Text::from(status),
+            Rect {
+                x: area.x,
+                y: area.bottom().saturating_sub(1),
+                width: area.width,
+                height: 1,
+            },
+        );
+    }
+
+    fn draw_board(&self, frame: &mut Frame) {
         let game = &self.life_game;
 
-        let color = Color::Indexed(self.setting.color);
+        let color = self.setting.color();
 
         let style_title = Style::default().bg(color).bold();
         let style_live = Style::default().bg(color);
         let style_dead = Style::default().bg(Color::White);
+        let style_wrap = Style::default().bg(Color::Yellow);
+        let style_born = Style::default().bg(Color::Green);
+        let style_died = Style::default().bg(Color::Red);
+        let style_cursor_live = Style::default().bg(Color::Blue);
+        let style_cursor_dead = Style::default().bg(Color::Cyan);
+
+        let brush: HashSet<(usize, usize)> = match self.mode {
+            Mode::Edit => self.brush().into_iter().collect(),
+            Mode::Run => HashSet::new(),
+        };
+
+        let wrap_x = game.edge_mode().wraps_x();
+        let wrap_y = game.edge_mode().wraps_y();
 
-        let title = Text::from(game.name()).style(style_title);
+        let title = Text::from(self.title()).style(style_title);
         let title_height = title.height() as u16;
 
         let width = self.setting.size * 2;
         let height = self.setting.size;
 
+        let area = frame.area();
+        let board_width = game.width() as i32 * width as i32;
+        let board_height = game.height() as i32 * height as i32;
+        let left = self.setting.x;
+        let top = self.setting.y + title_height as i32;
+
+        if wrap_x || wrap_y {
+            let mut borders = Borders::NONE;
+            if wrap_x {
+                borders |= Borders::LEFT | Borders::RIGHT;
+            }
+            if wrap_y {
+                borders |= Borders::TOP | Borders::BOTTOM;
+            }
+            frame.render_widget(
+                Block::new()
+                    .borders(borders)
+                    .border_style(Style::default().fg(Color::Yellow)),
+                place(area, left - 1, top - 1, board_width + 2, board_height + 2),
+            );
+        }
+
+        let title_width = board_width.max(title.width() as i32);
+        frame.render_widget(
+            title.centered(),
+            place(
+                area,
+                left - (title_width - board_width) / 2,
+                self.setting.y,
+                title_width,
+                title_height as i32,
+            ),
+        );
+
+        let hex = game.neighborhood() == Neighborhood::Hex;
+        let cell = |x: usize, y: usize| {
+            let shift = if hex && y % 2 == 1 {
+                width as i32 / 2
+            } else {
+                0
+            };
+            place(
+                area,
+                x as i32 * width as i32 + left + shift,
+                y as i32 * height as i32 + top,
+                width as i32,
+                height as i32,
+            )
+        };
+
+        frame.render_widget(
+            Block::default().style(style_dead),
+            place(area, left, top, board_width, board_height),
+        );
+
+        for (x, y, state) in game.decaying_cells() {
+            let level = 232 + (state - 1) as u16 * 23 / (game.states() - 1) as u16;
+            frame.render_widget(
+                Block::default().style(Style::default().bg(Color::Indexed(level as u8))),
+                cell(x, y),
+            );
+        }
+
+        for (&(x, y), &alive) in &self.changes {
+            if !alive {
+                frame.render_widget(Block::default().style(style_died), cell(x, y));
+            }
+        }
+
+        for &(x, y) in &brush {
+            frame.render_widget(Block::default().style(style_cursor_dead), cell(x, y));
+        }
+
+        for (x, y) in game.live_cells() {
+            let edge = (wrap_x && (x == 0 || x + 1 == game.width() as usize))
+                || (wrap_y && (y == 0 || y + 1 == game.height() as usize));
+            let style = match self.changes.get(&(x, y)) {
+                _ if brush.contains(&(x, y)) => style_cursor_live,
+                Some(true) => style_born,
+                _ if edge => style_wrap,
+                _ => style_live,
+            };
+            frame.render_widget(Block::default().style(style), cell(x, y));
+        }
+
+        if self.counting && self.setting.size >= COUNTS_MIN_SIZE {
+            let style_count = Style::default().fg(Color::Black);
+            for y in 0..game.height() as usize {
+                for x in 0..game.width() as usize {
+                    let count = game.neighbor_count(x, y).to_string();
+                    frame
+                        .render_widget(Text::from(count).style(style_count).centered(), cell(x, y));
+                }
+            }
+        }
+    }
+
+    fn draw_overview(&self, frame: &mut Frame) {
+        let game = &self.life_game;
+        let area = frame.area();
+
+        let title =
+            Text::from(self.title()).style(Style::default().bg(self.setting.color()).bold());
+        let title_height = title.height() as u16;
         frame.render_widget(
             title.centered(),
             Rect {
-                x: self.setting.x,
-                y: self.setting.y,
-                width: game.width() as u16 * width,
-                height: title_height,
+                height: title_height.min(area.height),
+                ..area
+            },
+        );
+
+        let columns = (area.width as usize).max(1);
+        let rows = (area.height.saturating_sub(title_height + 1) as usize).max(1);
+        let width = game.width() as usize;
+        let height = game.height() as usize;
+        let block = width
+            .div_ceil(columns)
+            .max(height.div_ceil(rows * 2))
+            .max(1);
+
+        let mut counts = vec![vec![0; width.div_ceil(block)]; height.div_ceil(block * 2)];
+        for (x, y) in game.live_cells() {
+            if let Some(count) = counts
+                .get_mut(y / (block * 2))
+                .and_then(|row| row.get_mut(x / block))
+            {
+                *count += 1;
+            }
+        }
+
+        let cells_per_block = (block * block * 2) as f64;
+        let lines: Vec<String> = counts
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&count| {
+                        let level = (count as f64 / cells_per_block * 4.0).ceil() as usize;
+                        SHADES[level.min(SHADES.len() - 1)]
+                    })
+                    .collect()
+            })
+            .collect();
+
+        frame.render_widget(
+            Paragraph::new(lines.join("\n"))
+                .style(Style::default().fg(self.setting.color()).bg(Color::White)),
+            Rect {
+                x: area.x,
+                y: area.y + title_height,
+                width: (counts.first().map_or(0, Vec::len) as u16).min(area.width),
+                height: (counts.len() as u16).min(rows as u16),
             },
         );
+    }
+
+    fn title(&self) -> String {
+        let title = format!(
+            "{}  Gen: {}",
+            self.life_game.name(),
+            self.life_game.generation()
+        );
+        if self.paused {
+            format!("{}  [paused]", title)
+        } else {
+            title
+        }
+    }
+
+    fn status(&self) -> String {
+        let mut status = vec![
+            format!("rule: {}", self.life_game.rule()),
+            format!("edges: {}", self.life_game.edge_mode()),
+            format!("neighborhood: {}", self.life_game.neighborhood()),
+        ];
+        status.push(format!("tick: {}ms", self.setting.tick_rate.as_millis()));
+        status.push(format!("pop: {}", self.life_game.population()));
+        if self.life_game.max_population() > 0 {
+            status.push(format!(
+                "peak: {} at gen {}",
+                self.life_game.max_population(),
+                self.life_game.peak_generation()
+            ));
+        }
+        if let Some(ratio) = self.life_game.growth_ratio() {
+            status.push(format!("growth: {:.2}", ratio));
+            status.push(format!(
+                "+{}/-{}",
+                self.life_game.last_births(),
+                self.life_game.last_deaths()
+            ));
+        }
+        if self.mode == Mode::Edit {
+            status.push("mode: edit".into());
+            status.push(format!("brush: {}", self.setting.brush_size));
+        }
+        if self.counts {
+            status.push("counts".into());
+        }
+        if self.follow {
+            status.push("follow".into());
+        }
+        if self.paused {
+            status.push("paused".into());
+        }
+        if let Some(generation) = self.breakpoint {
+            status.push(format!("break: {}", generation));
+        }
+        if let Some(input) = &self.prompt {
+            status.push(format!("break at gen: {}_", input));
+        }
+        if let Some((message, at)) = &self.message {
+            if at.elapsed() < MESSAGE_DURATION {
+                status.push(message.clone());
+            }
+        }
+        if let Some(density) = self.density {
+            status.push(format!("density: {}%", density * 100.0));
+        }
+        status.join("  ")
+    }
+}
 
-        for (y, rows) in game.cells_iter().enumerate() {
-            let y = y as u16 * height + title_height + self.setting.y;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(patterns: &[LifeGame]) -> (App<'_>, Instant) {
+        let mut app = App::new(patterns, 0, false);
+        app.life_game = LifeGame::new(10, 10);
+        app.life_game
+            .set_alives(&[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+        let start = Instant::now();
+        app.last_tick = start;
+        (app, start)
+    }
+
+    #[test]
+    fn steps_once_tick_rate_has_passed() {
+        let (mut app, start) = app(&[]);
+        let tick_rate = app.setting.tick_rate;
+
+        assert!(matches!(app.on_tick(start + tick_rate / 2), Tick::Idle));
+        assert_eq!(app.life_game.generation(), 0);
+
+        assert!(matches!(app.on_tick(start + tick_rate), Tick::Stepped));
+        assert_eq!(app.life_game.generation(), 1);
+
+        assert!(matches!(app.on_tick(start + tick_rate * 3 / 2), Tick::Idle));
+        assert!(matches!(app.on_tick(start + tick_rate * 2), Tick::Stepped));
+        assert_eq!(app.life_game.generation(), 2);
+    }
 
-            for (x, col) in rows.enumerate() {
-                frame.render_widget(
-                    Block::default().style(if col { style_live } else { style_dead }),
-                    Rect {
-                        x: x as u16 * width + self.setting.x,
-                        y,
-                        height,
-                        width,
-                    },
-                );
+    #[test]
+    fn stays_idle_while_paused_editing_or_in_help() {
+        let setups: [fn(&mut App); 3] = [
+            |app| app.paused = true,
+            |app| app.mode = Mode::Edit,
+            |app| app.help = true,
+        ];
+        for setup in setups {
+            let (mut app, start) = app(&[]);
+            let tick_rate = app.setting.tick_rate;
+            setup(&mut app);
+            for step in 1..=3 {
+                assert!(matches!(app.on_tick(start + tick_rate * step), Tick::Idle));
             }
+            assert_eq!(app.life_game.generation(), 0);
         }
     }
 }
@@ -0,0 +1,230 @@
+use crossterm::event::KeyCode;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    NextPattern,
+    Restart,
+    Faster,
+    Slower,
+    ZoomIn,
+    ZoomOut,
+    NextColor,
+    NudgeRed,
+    NudgeGreen,
+    NudgeBlue,
+    AutoFit,
+    Invert,
+    Symmetrize,
+    LaunchGlider,
+    Reseed,
+    Explain,
+    NeighborCounts,
+    EdgeMode,
+    Neighborhood,
+    Follow,
+    Overview,
+    Duplicate,
+    DuplicateCloser,
+    DuplicateFarther,
+    ExportPng,
+    SaveRle,
+    Edit,
+    MoveRight,
+    MoveLeft,
+    MoveDown,
+    MoveUp,
+    Step,
+    Back,
+    Pause,
+    Breakpoint,
+    Help,
+    CursorRight,
+    CursorLeft,
+    CursorDown,
+    CursorUp,
+    ToggleCell,
+    BrushSmaller,
+    BrushLarger,
+    Run,
+}
+
+pub struct Binding {
+    pub keys: &'static [KeyCode],
+    pub action: Action,
+    pub description: &'static str,
+}
+
+const fn bind(keys: &'static [KeyCode], action: Action, description: &'static str) -> Binding {
+    Binding {
+        keys,
+        action,
+        description,
+    }
+}
+
+pub const RUN_BINDINGS: &[Binding] = &[
+    bind(&[KeyCode::Char('q')], Action::Quit, "quit"),
+    bind(&[KeyCode::Char('n')], Action::NextPattern, "next pattern"),
+    bind(&[KeyCode::Char('r')], Action::Restart, "restart pattern"),
+    bind(&[KeyCode::Char(' ')], Action::Step, "step now"),
+    bind(&[KeyCode::Char('b')], Action::Back, "step back and pause"),
+    bind(&[KeyCode::Char('p')], Action::Pause, "pause or resume"),
+    bind(
+        &[KeyCode::Char('g')],
+        Action::Breakpoint,
+        "pause at a generation",
+    ),
+    bind(&[KeyCode::Char(']')], Action::Faster, "speed up"),
+    bind(&[KeyCode::Char('[')], Action::Slower, "slow down"),
+    bind(&[KeyCode::Char('+')], Action::ZoomIn, "zoom in"),
+    bind(&[KeyCode::Char('-')], Action::ZoomOut, "zoom out"),
+    bind(
+        &[KeyCode::Char('f')],
+        Action::AutoFit,
+        "toggle auto-fit zoom",
+    ),
+    bind(
+        &[KeyCode::Right, KeyCode::Char('l')],
+        Action::MoveRight,
+        "move right",
+    ),
+    bind(
+        &[KeyCode::Left, KeyCode::Char('h')],
+        Action::MoveLeft,
+        "move left",
+    ),
+    bind(
+        &[KeyCode::Down, KeyCode::Char('j')],
+        Action::MoveDown,
+        "move down",
+    ),
+    bind(
+        &[KeyCode::Up, KeyCode::Char('k')],
+        Action::MoveUp,
+        "move up",
+    ),
+    bind(&[KeyCode::Char('F')], Action::Follow, "toggle follow"),
+    bind(&[KeyCode::Char('o')], Action::Overview, "toggle overview"),
+    bind(&[KeyCode::Char('c')], Action::NextColor, "next color"),
+    bind(&[KeyCode::Char('1')], Action::NudgeRed, "nudge red"),
+    bind(&[KeyCode::Char('2')], Action::NudgeGreen, "nudge green"),
+    bind(&[KeyCode::Char('3')], Action::NudgeBlue, "nudge blue"),
+    bind(&[KeyCode::Char('i')], Action::Invert, "invert board"),
+    bind(
+        &[KeyCode::Char('m')],
+        Action::Symmetrize,
+        "mirror top-left quadrant",
+    ),
+    bind(
+        &[KeyCode::Char('G')],
+        Action::LaunchGlider,
+        "launch glider from an edge",
+    ),
+    bind(
+        &[KeyCode::Char('X')],
+        Action::Reseed,
+        "reseed with next density",
+    ),
+    bind(
+        &[KeyCode::Char('x')],
+        Action::Explain,
+        "toggle explain mode",
+    ),
+    bind(
+        &[KeyCode::Char('N')],
+        Action::NeighborCounts,
+        "toggle neighbor counts",
+    ),
+    bind(&[KeyCode::Char('w')], Action::EdgeMode, "cycle edge mode"),
+    bind(
+        &[KeyCode::Char('v')],
+        Action::Neighborhood,
+        "cycle neighborhood",
+    ),
+    bind(
+        &[KeyCode::Char('d')],
+        Action::Duplicate,
+        "stamp mirrored copy",
+    ),
+    bind(
+        &[KeyCode::Char('<')],
+        Action::DuplicateCloser,
+        "decrease copy offset",
+    ),
+    bind(
+        &[KeyCode::Char('>')],
+        Action::DuplicateFarther,
+        "increase copy offset",
+    ),
+    bind(&[KeyCode::Char('s')], Action::SaveRle, "save as RLE"),
+    bind(&[KeyCode::Char('P')], Action::ExportPng, "export PNG"),
+    bind(&[KeyCode::Char('e')], Action::Edit, "toggle edit mode"),
+    bind(&[KeyCode::Char('?')], Action::Help, "toggle help"),
+];
+
+pub const EDIT_BINDINGS: &[Binding] = &[
+    bind(
+        &[KeyCode::Right, KeyCode::Char('l')],
+        Action::CursorRight,
+        "cursor right",
+    ),
+    bind(
+        &[KeyCode::Left, KeyCode::Char('h')],
+        Action::CursorLeft,
+        "cursor left",
+    ),
+    bind(
+        &[KeyCode::Down, KeyCode::Char('j')],
+        Action::CursorDown,
+        "cursor down",
+    ),
+    bind(
+        &[KeyCode::Up, KeyCode::Char('k')],
+        Action::CursorUp,
+        "cursor up",
+    ),
+    bind(&[KeyCode::Char(' ')], Action::ToggleCell, "toggle cells"),
+    bind(&[KeyCode::Char(',')], Action::BrushSmaller, "smaller brush"),
+    bind(&[KeyCode::Char('.')], Action::BrushLarger, "larger brush"),
+    bind(&[KeyCode::Enter], Action::Run, "back to run mode"),
+];
+
+pub fn find(bindings: &[Binding], code: KeyCode) -> Option<Action> {
+    bindings
+        .iter()
+        .find(|binding| binding.keys.contains(&code))
+        .map(|binding| binding.action)
+}
+
+pub fn help_lines() -> Vec<String> {
+    let lines = |bindings: &[Binding]| {
+        bindings
+            .iter()
+            .map(|binding| {
+                let keys: Vec<String> = binding.keys.iter().map(|&key| label(key)).collect();
+                format!("{:<10} {}", keys.join("/"), binding.description)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut help = lines(RUN_BINDINGS);
+    help.push(String::new());
+    help.push("edit mode".into());
+    help.extend(lines(EDIT_BINDINGS));
+    help
+}
+
+fn label(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "space".into(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Right => "right".into(),
+        KeyCode::Left => "left".into(),
+        KeyCode::Down => "down".into(),
+        KeyCode::Up => "up".into(),
+        KeyCode::Enter => "enter".into(),
+        KeyCode::Esc => "esc".into(),
+        key => format!("{:?}", key),
+    }
+}
@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use image::{ImageReader, ImageResult, RgbaImage};
+
+use crate::{check_size, LifeGame, PatternError, LIVE, MAX_CELLS};
+
+impl LifeGame {
+    /// Loads an image as a board of the same size. Pixels darker than
+    /// `threshold` become live cells and brighter ones stay dead. Images with
+    /// more than `MAX_CELLS` pixels are rejected before they are decoded.
+    pub fn from_image<P: AsRef<Path>>(path: P, threshold: u8) -> Result<LifeGame, PatternError> {
+        let path = path.as_ref();
+        let (width, height) = ImageReader::open(path)?
+            .with_guessed_format()?
+            .into_dimensions()?;
+        let (width, height) = (width as usize, height as usize);
+
+        check_size(width, height, MAX_CELLS)?;
+
+        let pixels = ImageReader::open(path)?
+            .with_guessed_format()?
+            .decode()?
+            .into_luma8();
+
+        let mut game = LifeGame::new(width, height);
+        game.name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_uppercase())
+            .unwrap_or_default();
+
+        for (x, y, pixel) in pixels.enumerate_pixels() {
+            if pixel.0[0] < threshold {
+                game.cells[y as usize][x as usize] = LIVE;
+            }
+        }
+        Ok(game)
+    }
+
+    /// Writes the board as a PNG, see `render_rgba`.
+    pub fn save_png<P: AsRef<Path>>(
+        &self,
+        path: P,
+        cell_size: usize,
+        live: [u8; 4],
+        dead: [u8; 4],
+    ) -> ImageResult<()> {
+        let width = (self.width * cell_size) as u32;
+        let height = (self.height * cell_size) as u32;
+        let pixels = self.render_rgba(cell_size, live, dead);
+        RgbaImage::from_raw(width, height, pixels)
+            .expect("render_rgba fills the whole image")
+            .save(path)
+    }
+}
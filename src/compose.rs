@@ -0,0 +1,146 @@
+use crate::{check_size, LifeGame, PatternError, DEAD, LIVE, MAX_CELLS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoolOp {
+    And,
+    Or,
+    Xor,
+    /// Live in the first board and dead in the second.
+    AndNot,
+}
+
+impl BoolOp {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            BoolOp::And => a && b,
+            BoolOp::Or => a || b,
+            BoolOp::Xor => a != b,
+            BoolOp::AndNot => a && !b,
+        }
+    }
+}
+
+impl LifeGame {
+    /// Copies the live cells of `pattern` onto the board with its top-left
+    /// corner at `(x, y)`. Cells falling outside the board are clipped and dead
+    /// cells of `pattern` leave the board untouched.
+    pub fn stamp(&mut self, pattern: &LifeGame, x: usize, y: usize) {
+        for (dy, row) in pattern.cells_iter().enumerate() {
+            for (dx, alive) in row.enumerate() {
+                if !alive {
+                    continue;
+                }
+                if let Some(cell) = self
+                    .cells
+                    .get_mut(y + dy)
+                    .and_then(|row| row.get_mut(x + dx))
+                {
+                    *cell = LIVE;
+                }
+            }
+        }
+    }
+
+    /// Repeats `tile` in a `cols` x `rows` grid with `gap` dead cells between
+    /// copies, sizing the board to fit.
+    pub fn tiled(name: &str, tile: &LifeGame, cols: usize, rows: usize, gap: usize) -> LifeGame {
+        let width = tile.width * cols + gap * cols.saturating_sub(1);
+        let height = tile.height * rows + gap * rows.saturating_sub(1);
+
+        let mut game = tile.with_cells(vec![vec![DEAD; width]; height]);
+        game.name = name.into();
+        game.generation = 0;
+
+        for row in 0..rows {
+            for col in 0..cols {
+                game.stamp(tile, col * (tile.width + gap), row * (tile.height + gap));
+            }
+        }
+        game
+    }
+
+    /// Combines two boards of the same size cell by cell, keeping the
+    /// settings of `self`.
+    pub fn combine(&self, other: &LifeGame, op: BoolOp) -> Result<LifeGame, PatternError> {
+        if (self.width, self.height) != (other.width, other.height) {
+            return Err(PatternError::DimensionMismatch {
+                expected: (self.width, self.height),
+                actual: (other.width, other.height),
+            });
+        }
+
+        let cells = self
+            .cells_iter()
+            .zip(other.cells_iter())
+            .map(|(a, b)| {
+                a.zip(b)
+                    .map(|(a, b)| if op.apply(a, b) { LIVE } else { DEAD })
+                    .collect()
+            })
+            .collect();
+        Ok(self.with_cells(cells))
+    }
+
+    pub(crate) fn placed(
+        &self,
+        board_w: usize,
+        board_h: usize,
+        off_x: usize,
+        off_y: usize,
+    ) -> Result<LifeGame, PatternError> {
+        check_size(board_w, board_h, MAX_CELLS)?;
+        if off_x >= board_w || off_y >= board_h {
+            return Err(PatternError::OutOfBounds { x: off_x, y: off_y });
+        }
+
+        let mut game = self.with_cells(vec![vec![DEAD; board_w]; board_h]);
+        game.stamp(self, off_x, off_y);
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiled_population_scales_with_copies() {
+        let mut tile = LifeGame::new(3, 3);
+        tile.set_alives(&[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+        let game = LifeGame::tiled("", &tile, 3, 2, 1);
+        assert_eq!((game.width, game.height), (11, 7));
+        assert_eq!(game.population(), tile.population() * 3 * 2);
+    }
+
+    #[test]
+    fn combines_cells_per_operation() {
+        let mut a = LifeGame::new(2, 2);
+        a.set_alives(&[(0, 0), (1, 0)]);
+        let mut b = LifeGame::new(2, 2);
+        b.set_alives(&[(1, 0), (1, 1)]);
+
+        for (op, expected) in [
+            (BoolOp::And, vec![(1, 0)]),
+            (BoolOp::Or, vec![(0, 0), (1, 0), (1, 1)]),
+            (BoolOp::Xor, vec![(0, 0), (1, 1)]),
+            (BoolOp::AndNot, vec![(0, 0)]),
+        ] {
+            let game = a.combine(&b, op).unwrap();
+            assert_eq!(game.live_cells().collect::<Vec<_>>(), expected, "{:?}", op);
+        }
+    }
+
+    #[test]
+    fn combine_rejects_mismatched_sizes() {
+        let error = LifeGame::new(2, 2)
+            .combine(&LifeGame::new(3, 2), BoolOp::Or)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            PatternError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (3, 2),
+            }
+        ));
+    }
+}
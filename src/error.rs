@@ -0,0 +1,112 @@
+use std::{error::Error, fmt::Display, io, path::PathBuf};
+
+use crate::{RuleParseError, MAX_SIDE};
+
+#[derive(Debug)]
+pub enum PatternError {
+    Empty,
+    InvalidHeader(String),
+    UnexpectedToken {
+        line: usize,
+        col: usize,
+        token: char,
+    },
+    DimensionMismatch {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    InvalidRule(RuleParseError),
+    OutOfBounds {
+        x: usize,
+        y: usize,
+    },
+    TooLarge {
+        width: usize,
+        height: usize,
+        max_cells: usize,
+    },
+    Io(io::Error),
+    #[cfg(feature = "image")]
+    Image(image::ImageError),
+    /// A pattern in a collection failed to parse. `index` is 0-based, but is
+    /// displayed 1-based to match the `PATTERN <n>` default names.
+    Pattern {
+        index: usize,
+        error: Box<PatternError>,
+    },
+    File {
+        path: PathBuf,
+        error: Box<PatternError>,
+    },
+}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternError::Empty => write!(f, "pattern is empty"),
+            PatternError::InvalidHeader(header) => write!(f, "invalid header: {}", header),
+            PatternError::UnexpectedToken { line, col, token } => {
+                write!(f, "unexpected token {:?} at {}:{}", token, line, col)
+            }
+            PatternError::DimensionMismatch { expected, actual } => write!(
+                f,
+                "dimension mismatch: expected {}x{}, got {}x{}",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+            PatternError::InvalidRule(error) => write!(f, "{}", error),
+            PatternError::OutOfBounds { x, y } => {
+                write!(f, "offset ({}, {}) is outside the board", x, y)
+            }
+            PatternError::TooLarge {
+                width,
+                height,
+                max_cells,
+            } => write!(
+                f,
+                "board of {}x{} exceeds the limit of {} cells or {} per side",
+                width, height, max_cells, MAX_SIDE
+            ),
+            PatternError::Io(error) => write!(f, "{}", error),
+            #[cfg(feature = "image")]
+            PatternError::Image(error) => write!(f, "{}", error),
+            PatternError::Pattern { index, error } => {
+                write!(f, "pattern {}: {}", index + 1, error)
+            }
+            PatternError::File { path, error } => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl Error for PatternError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PatternError::InvalidRule(error) => Some(error),
+            PatternError::Io(error) => Some(error),
+            #[cfg(feature = "image")]
+            PatternError::Image(error) => Some(error),
+            PatternError::Pattern { error, .. } | PatternError::File { error, .. } => {
+                Some(error.as_ref())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PatternError {
+    fn from(error: io::Error) -> Self {
+        PatternError::Io(error)
+    }
+}
+
+impl From<RuleParseError> for PatternError {
+    fn from(error: RuleParseError) -> Self {
+        PatternError::InvalidRule(error)
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image::ImageError> for PatternError {
+    fn from(error: image::ImageError) -> Self {
+        PatternError::Image(error)
+    }
+}
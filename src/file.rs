@@ -0,0 +1,64 @@
+use std::{
+    fs::{self, File},
+    io::BufReader,
+    path::Path,
+};
+
+use crate::{LifeGame, PatternError, MAX_CELLS};
+
+impl LifeGame {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<LifeGame, PatternError> {
+        LifeGame::from_file_with_limit(path, MAX_CELLS)
+    }
+
+    /// Same as `from_file`, but rejects boards with more than `max_cells`
+    /// cells before they are allocated.
+    pub fn from_file_with_limit<P: AsRef<Path>>(
+        path: P,
+        max_cells: usize,
+    ) -> Result<LifeGame, PatternError> {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_uppercase())
+            .unwrap_or_default();
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("cells") => {
+                LifeGame::from_plaintext_with_limit(&name, &fs::read_to_string(path)?, max_cells)
+            }
+            _ => LifeGame::from_rle_reader_with_limit(
+                &name,
+                BufReader::new(File::open(path)?),
+                max_cells,
+            ),
+        }
+    }
+}
+
+/// Loads every `.rle` and `.cells` file in `dir`, sorted by file name. Other
+/// files are ignored; the first file that fails to load aborts with an error
+/// naming it.
+pub fn load_directory<P: AsRef<Path>>(dir: P) -> Result<Vec<LifeGame>, PatternError> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let supported = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| matches!(extension, "rle" | "cells"));
+        if supported && path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            LifeGame::from_file(&path).map_err(|error| PatternError::File {
+                path,
+                error: Box::new(error),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,94 @@
+use std::fmt::Display;
+
+use crate::{Rule, Transition, Value, DEAD, LIVE, NEIGHBORS};
+
+/// A board whose size is known at compile time, stored inline without heap
+/// allocation. It always uses bounded edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedLifeGame<const W: usize, const H: usize> {
+    cells: [[Value; W]; H],
+    generation: usize,
+}
+
+impl<const W: usize, const H: usize> Default for FixedLifeGame<W, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize, const H: usize> Display for FixedLifeGame<W, H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in &self.cells {
+            for &cell in row {
+                write!(f, "{}", if cell == LIVE { "+" } else { "." })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const W: usize, const H: usize> FixedLifeGame<W, H> {
+    pub fn new() -> Self {
+        FixedLifeGame {
+            cells: [[DEAD; W]; H],
+            generation: 0,
+        }
+    }
+
+    pub fn set_alives(&mut self, points: &[(usize, usize)]) {
+        for &(x, y) in points {
+            self.cells[y][x] = LIVE;
+        }
+    }
+
+    pub fn cells_iter(&self) -> impl Iterator<Item = impl Iterator<Item = bool> + '_> + '_ {
+        self.cells.iter().map(|row| row.iter().map(|&c| c == LIVE))
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<()> {
+        let rule = Rule::default();
+        let mut next = [[DEAD; W]; H];
+
+        for (y, row) in next.iter_mut().enumerate() {
+            for (x, cell) in row.iter_mut().enumerate() {
+                if rule.next(self.cells[y][x] == LIVE, self.count_alives(x, y)) {
+                    *cell = LIVE;
+                }
+            }
+        }
+
+        if self.cells == next {
+            None
+        } else {
+            self.cells = next;
+            self.generation += 1;
+            Some(())
+        }
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn width(&self) -> u16 {
+        W as u16
+    }
+
+    pub fn height(&self) -> u16 {
+        H as u16
+    }
+
+    fn count_alives(&self, x: usize, y: usize) -> usize {
+        NEIGHBORS
+            .iter()
+            .filter(
+                |&&(dx, dy)| match (x.checked_add_signed(dx), y.checked_add_signed(dy)) {
+                    (Some(x), Some(y)) if x < W && y < H => self.cells[y][x] == LIVE,
+                    _ => false,
+                },
+            )
+            .count()
+    }
+}
@@ -1,23 +1,210 @@
-use std::{cmp, fmt::Display};
+mod analysis;
+#[cfg(feature = "gif")]
+mod animation;
+#[cfg(feature = "image")]
+mod bitmap;
+mod compose;
+mod error;
+mod file;
+mod fixed;
+mod patterns;
+mod plaintext;
+mod random;
+mod render;
+mod rle;
+mod rule;
+mod snapshot;
+mod stepper;
+mod transform;
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+    io::{self, Write},
+    mem,
+    sync::Arc,
+};
+
+pub use analysis::{
+    analyze_patterns, compare_rules, search_soups, Fate, OscillatorReport, SoupResult, StepOutcome,
+};
+pub use compose::BoolOp;
+pub use error::PatternError;
+pub use file::load_directory;
+pub use fixed::FixedLifeGame;
+pub use patterns::{pattern_at, pattern_by_name, patterns};
+pub use rule::{Rule, RuleParseError, Transition};
+pub use snapshot::BoardSnapshot;
+pub use stepper::Stepper;
+pub use transform::Symmetry;
 
 type Value = u8;
 type Cells = Vec<Vec<Value>>;
 
+pub const MAX_CELLS: usize = 16_000_000;
+
+/// The longest side a parsed board may have, so that `width` and `height` fit
+/// the `u16` they are reported as.
+pub const MAX_SIDE: usize = u16::MAX as usize;
+
+/// Rejects a `width` x `height` board with more than `max_cells` cells or a
+/// side longer than `MAX_SIDE`, including sizes whose cell count overflows
+/// `usize`.
+pub(crate) fn check_size(
+    width: usize,
+    height: usize,
+    max_cells: usize,
+) -> Result<(), PatternError> {
+    if width > MAX_SIDE
+        || height > MAX_SIDE
+        || width
+            .checked_mul(height)
+            .is_none_or(|cells| cells > max_cells)
+    {
+        return Err(PatternError::TooLarge {
+            width,
+            height,
+            max_cells,
+        });
+    }
+    Ok(())
+}
+
+const HISTORY_LIMIT: usize = 100;
+
 const LIVE: Value = 1;
 const DEAD: Value = 0;
 
+const NEIGHBORS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+const VON_NEUMANN: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const HEX_EVEN_ROW: [(isize, isize); 6] = [(-1, -1), (0, -1), (-1, 0), (1, 0), (-1, 1), (0, 1)];
+const HEX_ODD_ROW: [(isize, isize); 6] = [(0, -1), (1, -1), (-1, 0), (1, 0), (0, 1), (1, 1)];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The eight surrounding cells.
+    #[default]
+    Moore,
+    /// The four orthogonally adjacent cells.
+    VonNeumann,
+    /// The six cells around a hexagon, with odd rows shifted half a cell to
+    /// the right. Usually paired with `Rule::hex`.
+    Hex,
+}
+
+impl Neighborhood {
+    /// Returns the neighbour offsets for a cell in row `y`.
+    pub fn offsets(self, y: usize) -> &'static [(isize, isize)] {
+        match self {
+            Neighborhood::Moore => &NEIGHBORS,
+            Neighborhood::VonNeumann => &VON_NEUMANN,
+            Neighborhood::Hex if y % 2 == 1 => &HEX_ODD_ROW,
+            Neighborhood::Hex => &HEX_EVEN_ROW,
+        }
+    }
+}
+
+impl Display for Neighborhood {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Neighborhood::Moore => write!(f, "Moore"),
+            Neighborhood::VonNeumann => write!(f, "von Neumann"),
+            Neighborhood::Hex => write!(f, "hex"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeMode {
+    #[default]
+    Bounded,
+    Toroidal,
+    LiveBorder,
+    /// Wraps left-right only; the top and bottom edges are dead.
+    HorizontalCylinder,
+    /// Wraps top-bottom only; the left and right edges are dead.
+    VerticalCylinder,
+}
+
+impl EdgeMode {
+    pub fn wraps_x(self) -> bool {
+        matches!(self, EdgeMode::Toroidal | EdgeMode::HorizontalCylinder)
+    }
+
+    pub fn wraps_y(self) -> bool {
+        matches!(self, EdgeMode::Toroidal | EdgeMode::VerticalCylinder)
+    }
+}
+
+impl Display for EdgeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EdgeMode::Bounded => write!(f, "bounded"),
+            EdgeMode::Toroidal => write!(f, "toroidal"),
+            EdgeMode::LiveBorder => write!(f, "live border"),
+            EdgeMode::HorizontalCylinder => write!(f, "horizontal cylinder"),
+            EdgeMode::VerticalCylinder => write!(f, "vertical cylinder"),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct LifeGame {
     name: String,
     width: usize,
     height: usize,
     cells: Cells,
+    generation: usize,
+    previous_population: Option<usize>,
+    max_population: usize,
+    peak_generation: usize,
+    last_births: usize,
+    last_deaths: usize,
+    history: VecDeque<Cells>,
+    history_limit: usize,
+    recent_hashes: VecDeque<u64>,
+    initial: Option<Cells>,
+    edge_mode: EdgeMode,
+    neighborhood: Neighborhood,
+    states: u8,
+    rule: Rule,
+    transition: Option<Arc<dyn Transition>>,
+}
+
+/// Summarises the board; use `Display` to print the cells.
+impl Debug for LifeGame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LifeGame")
+            .field("name", &self.name)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("generation", &self.generation)
+            .field("population", &self.population())
+            .finish()
+    }
 }
 
 impl Display for LifeGame {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for row in &self.cells {
             for &cell in row {
-                write!(f, "{}", if cell == LIVE { "+" } else { "." })?;
+                let glyph = match cell {
+                    LIVE => "+",
+                    DEAD => ".",
+                    _ => "*",
+                };
+                write!(f, "{}", glyph)?;
             }
             writeln!(f)?;
         }
@@ -34,6 +221,21 @@ impl LifeGame {
             cells: (0..height)
                 .map(|_| (0..width).map(|_| DEAD).collect())
                 .collect(),
+            generation: 0,
+            previous_population: None,
+            max_population: 0,
+            peak_generation: 0,
+            last_births: 0,
+            last_deaths: 0,
+            history: VecDeque::new(),
+            history_limit: HISTORY_LIMIT,
+            recent_hashes: VecDeque::new(),
+            initial: None,
+            edge_mode: EdgeMode::default(),
+            neighborhood: Neighborhood::default(),
+            states: 2,
+            rule: Rule::default(),
+            transition: None,
         }
     }
 
@@ -46,33 +248,432 @@ impl LifeGame {
             width,
             height,
             cells,
+            ..LifeGame::new(0, 0)
         }
     }
 
+    /// Brings the given cells to life, ignoring points outside the board.
     pub fn set_alives(&mut self, points: &[(usize, usize)]) {
         for &(x, y) in points {
-            self.cells[y][x] = LIVE;
+            self.set(x, y, true);
+        }
+    }
+
+    /// Kills the given cells, ignoring points outside the board.
+    pub fn set_dead(&mut self, points: &[(usize, usize)]) {
+        for &(x, y) in points {
+            self.set(x, y, false);
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        self.get_value(x, y).map(|cell| cell == LIVE)
+    }
+
+    pub fn toggle(&mut self, x: usize, y: usize) {
+        if let Some(cell) = self.cells.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *cell = if *cell == LIVE { DEAD } else { LIVE };
+        }
+    }
+
+    /// Sets a single cell and returns whether `(x, y)` was on the board.
+    pub fn set(&mut self, x: usize, y: usize, alive: bool) -> bool {
+        match self.cells.get_mut(y).and_then(|row| row.get_mut(x)) {
+            Some(cell) => {
+                *cell = if alive { LIVE } else { DEAD };
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Restores the cells to how they were before the first step, including
+    /// any edits made up to then, and resets the generation and statistics.
+    pub fn reset(&mut self) {
+        let initial = self.initial.take();
+        self.restart();
+        if let Some(cells) = initial {
+            self.cells = cells;
+        }
+    }
+
+    /// Kills every cell and resets the generation and population statistics.
+    pub fn clear(&mut self) {
+        for cell in self.cells.iter_mut().flatten() {
+            *cell = DEAD;
         }
+        self.restart();
+    }
+
+    pub fn invert(&mut self) {
+        for cell in self.cells.iter_mut().flatten() {
+            *cell = if *cell == LIVE { DEAD } else { LIVE };
+        }
+    }
+
+    /// Returns the raw value of a cell, or `None` outside the board.
+    ///
+    /// `Index<(usize, usize)>` is deliberately not implemented: it would have
+    /// to panic on out-of-range coordinates, and this forces callers to handle
+    /// the bounds instead.
+    pub fn get_value(&self, x: usize, y: usize) -> Option<Value> {
+        self.cells.get(y)?.get(x).copied()
+    }
+
+    /// Returns whether the cell at a signed coordinate is alive, resolving
+    /// coordinates outside the board through the edge mode: dead when
+    /// bounded, alive with a live border, and wrapped when toroidal.
+    pub fn live_at(&self, x: isize, y: isize) -> bool {
+        match self.locate(x, y) {
+            Some((x, y)) => self.cells[y][x] == LIVE,
+            None => self.edge_mode == EdgeMode::LiveBorder,
+        }
+    }
+
+    /// Counts the live neighbours of a cell, following the edge mode and
+    /// neighbourhood.
+    pub fn neighbor_count(&self, x: usize, y: usize) -> usize {
+        self.neighborhood
+            .offsets(y)
+            .iter()
+            .filter(|&&(dx, dy)| self.live_at(x as isize + dx, y as isize + dy))
+            .count()
     }
 
     pub fn cells_iter(&self) -> impl Iterator<Item = impl Iterator<Item = bool> + '_> + '_ {
         self.cells.iter().map(|row| row.iter().map(|&c| c == LIVE))
     }
 
+    pub fn population(&self) -> usize {
+        self.cells
+            .iter()
+            .flatten()
+            .filter(|&&cell| cell == LIVE)
+            .count()
+    }
+
+    pub fn live_cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.cells.iter().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|&(_, &cell)| cell == LIVE)
+                .map(move |(x, _)| (x, y))
+        })
+    }
+
+    /// Returns the `(start_x, length)` runs of consecutive live cells in row
+    /// `y`, or `None` if the row is outside the board.
+    pub fn row_runs(&self, y: usize) -> Option<Vec<(usize, usize)>> {
+        let mut runs: Vec<(usize, usize)> = Vec::new();
+        for (x, &cell) in self.cells.get(y)?.iter().enumerate() {
+            if cell != LIVE {
+                continue;
+            }
+            match runs.last_mut() {
+                Some((start, length)) if *start + *length == x => *length += 1,
+                _ => runs.push((x, 1)),
+            }
+        }
+        Some(runs)
+    }
+
+    pub fn to_bool_grid(&self) -> Vec<Vec<bool>> {
+        self.cells_iter().map(Iterator::collect).collect()
+    }
+
+    /// Overwrites every cell from `grid`, which must have exactly the board's
+    /// dimensions. The board is left untouched on a mismatch.
+    pub fn set_from_bool_grid(&mut self, grid: &[Vec<bool>]) -> Result<(), PatternError> {
+        let width = grid
+            .iter()
+            .map(Vec::len)
+            .find(|&len| len != self.width)
+            .unwrap_or(self.width);
+        if width != self.width || grid.len() != self.height {
+            return Err(PatternError::DimensionMismatch {
+                expected: (self.width, self.height),
+                actual: (width, grid.len()),
+            });
+        }
+
+        for (row, values) in self.cells.iter_mut().zip(grid) {
+            for (cell, &alive) in row.iter_mut().zip(values) {
+                *cell = if alive { LIVE } else { DEAD };
+            }
+        }
+        Ok(())
+    }
+
+    /// Yields the position and state of every cell that is decaying under a
+    /// Generations rule.
+    pub fn decaying_cells(&self) -> impl Iterator<Item = (usize, usize, u8)> + '_ {
+        self.cells.iter().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|&(_, &cell)| cell != LIVE && cell != DEAD)
+                .map(move |(x, &cell)| (x, y, cell))
+        })
+    }
+
+    pub fn columns_iter(&self) -> impl Iterator<Item = impl Iterator<Item = bool> + '_> + '_ {
+        (0..self.width).map(move |x| self.cells.iter().map(move |row| row[x] == LIVE))
+    }
+
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Option<()> {
         let next = self.to_next_cells();
         if self.cells == next {
             None
         } else {
-            self.cells = next;
+            self.step_to(next);
             Some(())
         }
     }
 
+    /// Returns whether a step would leave the board unchanged, as for a still
+    /// life or an empty board, without stepping.
+    pub fn is_initially_stable(&self) -> bool {
+        self.to_next_cells() == self.cells
+    }
+
+    /// Restores the board as it was before the last recorded step and moves
+    /// the generation back, or returns `None` when the history is empty.
+    pub fn prev(&mut self) -> Option<()> {
+        self.cells = self.history.pop_back()?;
+        self.generation = self.generation.saturating_sub(1);
+        self.previous_population = None;
+        self.last_births = 0;
+        self.last_deaths = 0;
+        Some(())
+    }
+
+    /// Keeps up to `limit` previous boards for `prev`, dropping the oldest
+    /// first. The default is 100, and 0 records no history.
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history_limit = limit;
+        while self.history.len() > limit {
+            self.history.pop_front();
+        }
+    }
+
+    /// Steps up to `steps` generations, stopping early once the board is
+    /// stable, and returns how many generations were advanced.
+    pub fn advance(&mut self, steps: usize) -> usize {
+        (0..steps).take_while(|_| self.next().is_some()).count()
+    }
+
+    /// Advances one generation even when the board is stable.
+    ///
+    /// Unlike `next`, this never short-circuits on stability: the cells are
+    /// always recomputed and the generation always increments, so callers can
+    /// treat stepping as infinite.
+    pub fn next_forced(&mut self) {
+        let next = self.to_next_cells();
+        self.step_to(next);
+    }
+
+    /// Advances only the cells inside the rectangle, clipped to the board.
+    ///
+    /// Every cell in the region is computed from the current state, reading
+    /// neighbours both inside and outside the region (following the edge
+    /// mode), so the region sees a frozen snapshot of its surroundings. Cells
+    /// outside the region and the generation counter are left untouched.
+    pub fn step_region(&mut self, x: usize, y: usize, w: usize, h: usize) {
+        let xs = x.min(self.width)..x.saturating_add(w).min(self.width);
+        let ys = y.min(self.height)..y.saturating_add(h).min(self.height);
+
+        let region: Vec<Vec<Value>> = ys
+            .clone()
+            .map(|y| {
+                xs.clone()
+                    .map(|x| self.to_next_cell(self.cells[y][x], x, y))
+                    .collect()
+            })
+            .collect();
+
+        for (y, row) in ys.zip(region) {
+            self.cells[y][xs.clone()].copy_from_slice(&row);
+        }
+    }
+
+    pub fn record_ascii(&mut self, max_steps: usize) -> String {
+        let mut frames = vec![format!("Generation {}\n{}", self.generation, self)];
+        for _ in 0..max_steps {
+            if self.next().is_none() {
+                break;
+            }
+            frames.push(format!("Generation {}\n{}", self.generation, self));
+        }
+        frames.join("---\n")
+    }
+
+    /// Steps the board until it is stable or `max_steps` generations have
+    /// passed, writing a `generation,population,births,deaths` header and then
+    /// one row per generation, starting with the current one.
+    pub fn run_to_csv<W: Write>(&mut self, max_steps: usize, w: &mut W) -> io::Result<()> {
+        writeln!(w, "generation,population,births,deaths")?;
+        writeln!(w, "{},{},0,0", self.generation, self.population())?;
+        for _ in 0..max_steps {
+            if self.next().is_none() {
+                break;
+            }
+            writeln!(
+                w,
+                "{},{},{},{}",
+                self.generation,
+                self.population(),
+                self.last_births,
+                self.last_deaths
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Lists the cells whose value differs in `other`, with their value there.
+    /// Boards of different sizes are compared over their overlapping area.
+    pub fn diff(&self, other: &LifeGame) -> Vec<(usize, usize, bool)> {
+        self.cells_iter()
+            .zip(other.cells_iter())
+            .enumerate()
+            .flat_map(|(y, (row, other_row))| {
+                row.zip(other_row)
+                    .enumerate()
+                    .filter(|(_, (cell, other_cell))| cell != other_cell)
+                    .map(move |(x, (_, other_cell))| (x, y, other_cell))
+            })
+            .collect()
+    }
+
+    /// Lists the cells that will be born or die on the next step, with their
+    /// upcoming value, without stepping. Matches `diff` against the next
+    /// generation.
+    pub fn pending_changes(&self) -> Vec<(usize, usize, bool)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter().enumerate().filter_map(move |(x, &cell)| {
+                    let alive = self.to_next_cell(cell, x, y) == LIVE;
+                    (alive != (cell == LIVE)).then_some((x, y, alive))
+                })
+            })
+            .collect()
+    }
+
+    /// Compares only the dimensions and cells, ignoring the name, generation
+    /// and other settings.
+    pub fn same_cells(&self, other: &LifeGame) -> bool {
+        self.width == other.width && self.height == other.height && self.cells == other.cells
+    }
+
+    /// Estimates the bytes used by the board, counting the `Vec<Vec<_>>`
+    /// headers as well as the cells themselves, and the saved history and
+    /// starting board.
+    pub fn memory_bytes(&self) -> usize {
+        mem::size_of::<LifeGame>()
+            + cells_bytes(&self.cells)
+            + self.history.capacity() * mem::size_of::<Cells>()
+            + self.history.iter().map(cells_bytes).sum::<usize>()
+            + self.initial.as_ref().map_or(0, cells_bytes)
+            + self.recent_hashes.capacity() * mem::size_of::<u64>()
+    }
+
+    /// Returns the population relative to the one before the last step, or
+    /// `None` before any step and when that population was 0. A ratio that
+    /// stays above 1 suggests unbounded growth, while one hovering around 1
+    /// suggests an oscillator.
+    pub fn growth_ratio(&self) -> Option<f64> {
+        self.previous_population
+            .filter(|&previous| previous > 0)
+            .map(|previous| self.population() as f64 / previous as f64)
+    }
+
+    /// Returns how many cells came alive on the last step.
+    pub fn last_births(&self) -> usize {
+        self.last_births
+    }
+
+    /// Returns how many live cells died on the last step.
+    pub fn last_deaths(&self) -> usize {
+        self.last_deaths
+    }
+
+    /// Returns the highest population seen when stepping, counting the board
+    /// both right before and right after each step.
+    pub fn max_population(&self) -> usize {
+        self.max_population
+    }
+
+    /// Returns the generation at which `max_population` was first reached.
+    pub fn peak_generation(&self) -> usize {
+        self.peak_generation
+    }
+
     pub fn name(&self) -> &str {
         self.name.as_ref()
     }
 
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn edge_mode(&self) -> EdgeMode {
+        self.edge_mode
+    }
+
+    pub fn set_edge_mode(&mut self, edge_mode: EdgeMode) {
+        self.edge_mode = edge_mode;
+    }
+
+    pub fn with_edge_mode(mut self, edge_mode: EdgeMode) -> Self {
+        self.set_edge_mode(edge_mode);
+        self
+    }
+
+    pub fn neighborhood(&self) -> Neighborhood {
+        self.neighborhood
+    }
+
+    /// Changes which cells count as neighbours from the next step on.
+    pub fn set_neighborhood(&mut self, neighborhood: Neighborhood) {
+        self.neighborhood = neighborhood;
+    }
+
+    pub fn states(&self) -> u8 {
+        self.states
+    }
+
+    /// Sets the number of cell states for Generations rules. With more than
+    /// two states a live cell that fails to survive does not die at once but
+    /// decays through states 2, 3, ... `states - 1` before becoming dead.
+    /// Decaying cells neither count as live neighbours nor can be born again
+    /// until they are dead. Values below 2 are treated as 2, plain Life.
+    pub fn set_states(&mut self, states: u8) {
+        self.states = states.max(2);
+    }
+
+    /// The B/S rule of the board. It is not consulted while a custom
+    /// transition set by `set_transition` is active.
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    pub fn set_rule(&mut self, rule: Rule) {
+        self.rule = rule;
+        self.transition = None;
+    }
+
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.set_rule(rule);
+        self
+    }
+
+    /// Replaces the B/S rule with a custom transition function until the next
+    /// `set_rule`.
+    pub fn set_transition<T: Transition + 'static>(&mut self, transition: T) {
+        self.transition = Some(Arc::new(transition));
+    }
+
     pub fn width(&self) -> u16 {
         self.width as u16
     }
@@ -81,6 +682,95 @@ impl LifeGame {
         self.height as u16
     }
 
+    /// Copies the board, settings and statistics for a throwaway run, leaving
+    /// out the saved history and recording none from then on.
+    pub(crate) fn detached(&self) -> LifeGame {
+        LifeGame {
+            name: self.name.clone(),
+            cells: self.cells.clone(),
+            history: VecDeque::new(),
+            history_limit: 0,
+            recent_hashes: VecDeque::new(),
+            initial: None,
+            rule: self.rule.clone(),
+            transition: self.transition.clone(),
+            ..*self
+        }
+    }
+
+    fn with_cells(&self, cells: Cells) -> LifeGame {
+        LifeGame {
+            name: self.name.clone(),
+            width: cells.iter().map(Vec::len).min().unwrap_or(0),
+            height: cells.len(),
+            cells,
+            generation: self.generation,
+            history_limit: self.history_limit,
+            edge_mode: self.edge_mode,
+            neighborhood: self.neighborhood,
+            states: self.states,
+            rule: self.rule.clone(),
+            transition: self.transition.clone(),
+            ..LifeGame::new(0, 0)
+        }
+    }
+
+    /// Swaps in the next cells, deriving the population before and after the
+    /// step and its births and deaths from a single pass over both grids.
+    fn step_to(&mut self, next: Cells) {
+        let (population, births, deaths) =
+            self.cells.iter().flatten().zip(next.iter().flatten()).fold(
+                (0, 0, 0),
+                |(population, births, deaths), (&before, &after)| match (
+                    before == LIVE,
+                    after == LIVE,
+                ) {
+                    (true, true) => (population + 1, births, deaths),
+                    (false, true) => (population, births + 1, deaths),
+                    (true, false) => (population + 1, births, deaths + 1),
+                    (false, false) => (population, births, deaths),
+                },
+            );
+        self.track_peak(population);
+        self.previous_population = Some(population);
+        self.last_births = births;
+        self.last_deaths = deaths;
+        if self.initial.is_none() {
+            self.initial = Some(self.cells.clone());
+        }
+        if self.history_limit > 0 {
+            if self.history.len() == self.history_limit {
+                self.history.pop_front();
+            }
+            self.history.push_back(mem::replace(&mut self.cells, next));
+        } else {
+            self.cells = next;
+        }
+        self.generation += 1;
+        self.track_peak(population + births - deaths);
+    }
+
+    /// Zeroes the generation and forgets the statistics and history of the
+    /// previous run.
+    fn restart(&mut self) {
+        self.generation = 0;
+        self.previous_population = None;
+        self.max_population = 0;
+        self.peak_generation = 0;
+        self.last_births = 0;
+        self.last_deaths = 0;
+        self.history.clear();
+        self.recent_hashes.clear();
+        self.initial = None;
+    }
+
+    fn track_peak(&mut self, population: usize) {
+        if population > self.max_population {
+            self.max_population = population;
+            self.peak_generation = self.generation;
+        }
+    }
+
     fn to_next_cells(&self) -> Cells {
         self.cells
             .iter()
@@ -95,18 +785,186 @@ impl LifeGame {
     }
 
     fn to_next_cell(&self, cell: Value, x: usize, y: usize) -> Value {
-        match (cell, self.count_alives(x, y)) {
-            (DEAD, 3) | (LIVE, 2) | (LIVE, 3) => LIVE,
+        let transition: &dyn Transition = match &self.transition {
+            Some(transition) => transition.as_ref(),
+            None => &self.rule,
+        };
+        match cell {
+            DEAD | LIVE if transition.next(cell == LIVE, self.neighbor_count(x, y)) => LIVE,
+            DEAD => DEAD,
+            _ if cell.saturating_add(1) < self.states => cell + 1,
             _ => DEAD,
         }
     }
 
-    fn count_alives(&self, x: usize, y: usize) -> usize {
-        let ys = if y == 0 { 0 } else { y - 1 }..=cmp::min(y + 1, self.height - 1);
-        let xs = if x == 0 { 0 } else { x - 1 }..=cmp::min(x + 1, self.width - 1);
-        ys.flat_map(|y| xs.clone().map(move |x| (x, y)))
-            .filter(|&p| p != (x, y))
-            .filter(|&(x, y)| self.cells[y][x] == LIVE)
-            .count()
+    fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS
+            .iter()
+            .filter_map(move |&(dx, dy)| self.locate(x as isize + dx, y as isize + dy))
+    }
+
+    fn locate(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let x = resolve(x, self.width, self.edge_mode.wraps_x())?;
+        let y = resolve(y, self.height, self.edge_mode.wraps_y())?;
+        Some((x, y))
+    }
+}
+
+fn cells_bytes(cells: &Cells) -> usize {
+    cells.capacity() * mem::size_of::<Vec<Value>>()
+        + cells
+            .iter()
+            .map(|row| row.capacity() * mem::size_of::<Value>())
+            .sum::<usize>()
+}
+
+fn resolve(value: isize, len: usize, wrap: bool) -> Option<usize> {
+    if wrap && len > 0 {
+        Some(value.rem_euclid(len as isize) as usize)
+    } else {
+        usize::try_from(value).ok().filter(|&value| value < len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(width: usize, height: usize, alives: &[(usize, usize)]) -> LifeGame {
+        let mut game = LifeGame::new(width, height);
+        game.set_alives(alives);
+        game
+    }
+
+    #[test]
+    fn invert_twice_restores_board() {
+        let original = board(4, 3, &[(0, 0), (1, 2), (3, 1)]);
+        let mut game = original.clone();
+        game.invert();
+        assert_eq!(game.population(), 12 - 3);
+        game.invert();
+        assert!(game.same_cells(&original));
+    }
+
+    #[test]
+    fn live_border_counts_outside_cells() {
+        let game = LifeGame::new(3, 3).with_edge_mode(EdgeMode::LiveBorder);
+        assert_eq!(game.neighbor_count(0, 0), 5);
+        assert_eq!(game.neighbor_count(1, 0), 3);
+        assert_eq!(game.neighbor_count(1, 1), 0);
+    }
+
+    #[test]
+    fn live_at_resolves_corners_by_edge_mode() {
+        let game = board(3, 3, &[(0, 0), (2, 2)]);
+        for (edge_mode, expected) in [
+            (EdgeMode::Bounded, false),
+            (EdgeMode::Toroidal, true),
+            (EdgeMode::LiveBorder, true),
+            (EdgeMode::HorizontalCylinder, false),
+            (EdgeMode::VerticalCylinder, false),
+        ] {
+            let game = game.clone().with_edge_mode(edge_mode);
+            assert_eq!(game.live_at(-1, -1), expected, "{}", edge_mode);
+            assert_eq!(game.live_at(3, 3), expected, "{}", edge_mode);
+            assert!(game.live_at(0, 0), "{}", edge_mode);
+            assert!(!game.live_at(2, 0), "{}", edge_mode);
+        }
+    }
+
+    #[test]
+    fn wraps_each_axis_independently() {
+        let game = board(3, 3, &[(2, 0), (0, 2)]);
+        for (edge_mode, wraps_x, wraps_y) in [
+            (EdgeMode::Bounded, false, false),
+            (EdgeMode::HorizontalCylinder, true, false),
+            (EdgeMode::VerticalCylinder, false, true),
+            (EdgeMode::Toroidal, true, true),
+        ] {
+            assert_eq!(edge_mode.wraps_x(), wraps_x, "{}", edge_mode);
+            assert_eq!(edge_mode.wraps_y(), wraps_y, "{}", edge_mode);
+
+            let game = game.clone().with_edge_mode(edge_mode);
+            assert_eq!(game.live_at(-1, 0), wraps_x, "{}", edge_mode);
+            assert_eq!(game.live_at(0, -1), wraps_y, "{}", edge_mode);
+            assert_eq!(
+                game.neighbor_count(0, 0),
+                wraps_x as usize + wraps_y as usize,
+                "{}",
+                edge_mode
+            );
+        }
+    }
+
+    #[test]
+    fn row_runs_splits_separate_runs() {
+        let game = board(9, 2, &[(0, 0), (1, 0), (3, 0), (5, 0), (6, 0), (7, 0)]);
+        assert_eq!(game.row_runs(0), Some(vec![(0, 2), (3, 1), (5, 3)]));
+        assert_eq!(game.row_runs(1), Some(vec![]));
+        assert_eq!(game.row_runs(2), None);
+    }
+
+    #[test]
+    fn blinker_population_stays_constant() {
+        let mut game = board(5, 5, &[(1, 2), (2, 2), (3, 2)]);
+        for _ in 0..4 {
+            game.next().unwrap();
+            assert_eq!(game.population(), 3);
+            assert_eq!((game.last_births(), game.last_deaths()), (2, 2));
+        }
+        assert_eq!(game.max_population(), 3);
+    }
+
+    #[test]
+    fn births_and_deaths_match_population_change() {
+        let mut game = board(20, 20, &[(9, 8), (10, 8), (8, 9), (9, 9), (9, 10)]);
+        for _ in 0..30 {
+            let before = game.population() as isize;
+            game.next().unwrap();
+            let change = game.population() as isize - before;
+            assert_eq!(
+                game.last_births() as isize - game.last_deaths() as isize,
+                change
+            );
+        }
+    }
+
+    #[test]
+    fn prev_restores_recorded_steps() {
+        let mut game = board(5, 5, &[(1, 2), (2, 2), (3, 2)]);
+        let start = game.clone();
+        game.next().unwrap();
+        game.next().unwrap();
+        game.prev().unwrap();
+        game.prev().unwrap();
+        assert!(game.same_cells(&start));
+        assert_eq!(game.generation(), 0);
+        assert_eq!(game.prev(), None);
+
+        game.set_history_limit(0);
+        game.next().unwrap();
+        assert_eq!(game.prev(), None);
+    }
+
+    #[test]
+    fn memory_bytes_counts_starting_board() {
+        let mut game = board(8, 8, &[(1, 2), (2, 2), (3, 2)]);
+        game.set_history_limit(0);
+        let before = game.memory_bytes();
+        game.next().unwrap();
+        assert_eq!(game.memory_bytes(), before + cells_bytes(&game.cells));
+    }
+
+    #[test]
+    fn growth_ratio_skips_empty_previous_board() {
+        let mut game = LifeGame::new(3, 3).with_edge_mode(EdgeMode::LiveBorder);
+        assert_eq!(game.growth_ratio(), None);
+        game.next().unwrap();
+        assert!(game.population() > 0);
+        assert_eq!(game.growth_ratio(), None);
+
+        let mut game = board(5, 5, &[(1, 2), (2, 2), (3, 2)]);
+        game.next().unwrap();
+        assert_eq!(game.growth_ratio(), Some(1.0));
     }
 }
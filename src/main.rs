@@ -1,113 +1,125 @@
 mod app;
 
 use app::App;
-use std::error::Error;
+use rust_life_game::{load_directory, LifeGame, PatternError};
+use std::{env, error::Error, path::PathBuf, process};
+
+#[cfg(feature = "gif")]
+const RECORD_CELL_SIZE: usize = 8;
+
+#[cfg(feature = "gif")]
+const RECORD_FRAMES: usize = 100;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    #[cfg(feature = "gif")]
+    if args.first().is_some_and(|arg| arg == "--record") {
+        if let Err(error) = record(&args) {
+            eprintln!("rust-life-game: {}", error);
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let (patterns, options) =
+        match options(&args).and_then(|options| Ok((patterns(&options)?, options))) {
+            Ok(loaded) => loaded,
+            Err(error) => {
+                eprintln!("rust-life-game: {}", error);
+                process::exit(1);
+            }
+        };
+
     let mut terminal = ratatui::init();
-    let inputs = inputs();
-    let mut app = App::new(&inputs);
+    let mut app = App::new(&patterns, options.skip, options.screensaver);
     let result = app.run(&mut terminal);
     ratatui::restore();
     result
 }
 
-fn inputs() -> Vec<(String, Vec<Vec<u8>>)> {
-    vec![
-        (
-            "octagon".to_uppercase(),
-            vec![
-                vec![0, 0, 0, 1, 1, 0, 0, 0],
-                vec![0, 0, 1, 0, 0, 1, 0, 0],
-                vec![0, 1, 0, 0, 0, 0, 1, 0],
-                vec![1, 0, 0, 0, 0, 0, 0, 1],
-                vec![1, 0, 0, 0, 0, 0, 0, 1],
-                vec![0, 1, 0, 0, 0, 0, 1, 0],
-                vec![0, 0, 1, 0, 0, 1, 0, 0],
-                vec![0, 0, 0, 1, 1, 0, 0, 0],
-            ],
-        ),
-        (
-            "glider".to_uppercase(),
-            vec![
-                vec![0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-            ],
-        ),
-        (
-            "twin-glider".to_uppercase(),
-            vec![
-                vec![0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
-                vec![1, 0, 1, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 1, 1, 0, 0, 0, 1, 1, 0, 0],
-                vec![0, 0, 0, 0, 0, 1, 0, 1, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-            ],
-        ),
-        (
-            "galaxy".to_uppercase(),
-            vec![
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 1, 1, 1, 1, 1, 1, 0, 1, 1, 0, 0, 0],
-                vec![0, 0, 0, 1, 1, 1, 1, 1, 1, 0, 1, 1, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-                vec![0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-                vec![0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-                vec![0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-                vec![0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 1, 1, 0, 1, 1, 1, 1, 1, 1, 0, 0, 0],
-                vec![0, 0, 0, 1, 1, 0, 1, 1, 1, 1, 1, 1, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-            ],
-        ),
-        (
-            "tree".to_uppercase(),
-            vec![
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
-                vec![0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
-                vec![0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-                vec![0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0],
-                vec![0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0],
-                vec![0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0],
-                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-            ],
-        ),
-    ]
+struct Options {
+    file: Option<PathBuf>,
+    dir: Option<String>,
+    skip: usize,
+    screensaver: bool,
+}
+
+fn options(args: &[String]) -> Result<Options, Box<dyn Error>> {
+    let mut options = Options {
+        file: None,
+        dir: None,
+        skip: 0,
+        screensaver: false,
+    };
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for {}", flag))
+        };
+        match flag.as_str() {
+            "--dir" => options.dir = Some(value()?.clone()),
+            "--skip" => options.skip = value()?.parse()?,
+            "--screensaver" => options.screensaver = true,
+            file if !file.starts_with("--") && options.file.is_none() => {
+                options.file = Some(file.into())
+            }
+            _ => return Err(format!("unexpected argument: {}", flag).into()),
+        }
+    }
+    if options.file.is_some() && options.dir.is_some() {
+        return Err("pass either a pattern file or --dir, not both".into());
+    }
+    Ok(options)
+}
+
+fn patterns(options: &Options) -> Result<Vec<LifeGame>, PatternError> {
+    if let Some(path) = &options.file {
+        let pattern = LifeGame::from_file(path).map_err(|error| PatternError::File {
+            path: path.clone(),
+            error: Box::new(error),
+        })?;
+        return Ok(vec![pattern]);
+    }
+    match options.dir.as_deref() {
+        Some(dir) => {
+            let patterns = load_directory(dir)?;
+            if patterns.is_empty() {
+                return Err(PatternError::Empty);
+            }
+            Ok(patterns)
+        }
+        None => Ok(rust_life_game::patterns()),
+    }
+}
+
+/// Runs `--record OUT --pattern FILE [--frames N]` without a terminal,
+/// writing the pattern's generations to an animated GIF.
+#[cfg(feature = "gif")]
+fn record(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut output = None;
+    let mut pattern = None;
+    let mut frames = RECORD_FRAMES;
+    for option in args.chunks(2) {
+        match option {
+            [flag, value] if flag == "--record" => output = Some(value),
+            [flag, value] if flag == "--pattern" => pattern = Some(value),
+            [flag, value] if flag == "--frames" => frames = value.parse()?,
+            _ => return Err(format!("unexpected argument: {}", option[0]).into()),
+        }
+    }
+    let (Some(output), Some(pattern)) = (output, pattern) else {
+        return Err("usage: --record OUT --pattern FILE [--frames N]".into());
+    };
+
+    LifeGame::from_file(pattern)?.save_gif(
+        output,
+        frames,
+        RECORD_CELL_SIZE,
+        [0, 0, 0, 255],
+        [255, 255, 255, 255],
+        app::TICK_RATE,
+    )?;
+    Ok(())
 }
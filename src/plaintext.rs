@@ -0,0 +1,57 @@
+use crate::{check_size, LifeGame, PatternError, DEAD, LIVE, MAX_CELLS};
+
+impl LifeGame {
+    /// Parses the plaintext `.cells` format. Lines starting with `!` are
+    /// comments, `O` is a live cell and any other character is dead. Shorter
+    /// rows are padded with dead cells to the longest row.
+    pub fn from_plaintext(name: &str, text: &str) -> Result<LifeGame, PatternError> {
+        LifeGame::from_plaintext_with_limit(name, text, MAX_CELLS)
+    }
+
+    /// Same as `from_plaintext`, but rejects patterns whose padded board would
+    /// have more than `max_cells` cells before it is allocated.
+    pub fn from_plaintext_with_limit(
+        name: &str,
+        text: &str,
+        max_cells: usize,
+    ) -> Result<LifeGame, PatternError> {
+        let rows: Vec<&str> = text
+            .lines()
+            .filter(|line| !line.starts_with('!'))
+            .map(str::trim_end)
+            .collect();
+
+        let width = rows.iter().map(|row| row.chars().count()).max();
+        let Some(width) = width.filter(|&width| width > 0) else {
+            return Err(PatternError::Empty);
+        };
+        check_size(width, rows.len(), max_cells)?;
+
+        let cells: Vec<Vec<_>> = rows
+            .iter()
+            .map(|row| {
+                let mut cells: Vec<_> = row
+                    .chars()
+                    .map(|c| if c == 'O' { LIVE } else { DEAD })
+                    .collect();
+                cells.resize(width, DEAD);
+                cells
+            })
+            .collect();
+
+        Ok(LifeGame::from(name, &cells))
+    }
+
+    /// Parses a plaintext pattern and places it at `(off_x, off_y)` on an
+    /// otherwise dead `board_w` x `board_h` board, clipping what falls outside.
+    pub fn from_plaintext_at(
+        name: &str,
+        text: &str,
+        board_w: usize,
+        board_h: usize,
+        off_x: usize,
+        off_y: usize,
+    ) -> Result<LifeGame, PatternError> {
+        LifeGame::from_plaintext(name, text)?.placed(board_w, board_h, off_x, off_y)
+    }
+}
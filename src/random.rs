@@ -0,0 +1,74 @@
+use crate::{LifeGame, DEAD, LIVE};
+
+/// A glider heading down and to the right.
+const GLIDER: [(usize, usize); 5] = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+
+const GLIDER_SIZE: usize = 3;
+
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        SplitMix64(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl LifeGame {
+    /// Builds a board whose cells are live with probability `density`, clamped
+    /// to `[0.0, 1.0]`. The same seed always produces the same board.
+    pub fn random(width: usize, height: usize, density: f64, seed: u64) -> LifeGame {
+        let mut game = LifeGame::new(width, height);
+        game.name = "RANDOM".into();
+        game.randomize(density, seed);
+        game
+    }
+
+    /// Reseeds the board in place, keeping its size and settings and
+    /// resetting the generation.
+    pub fn randomize(&mut self, density: f64, seed: u64) {
+        let density = density.clamp(0.0, 1.0);
+        let mut rng = SplitMix64::new(seed);
+        for cell in self.cells.iter_mut().flatten() {
+            *cell = if rng.next_f64() < density { LIVE } else { DEAD };
+        }
+        self.restart();
+    }
+
+    /// Stamps a glider at a random position along a random edge, flipped so
+    /// that it travels into the board. The same seed always picks the same
+    /// spot.
+    pub fn launch_glider(&mut self, seed: u64) {
+        let mut rng = SplitMix64::new(seed);
+        let max_x = self.width.saturating_sub(GLIDER_SIZE);
+        let max_y = self.height.saturating_sub(GLIDER_SIZE);
+        let mut along = |max: usize| (rng.next_u64() % (max as u64 + 1)) as usize;
+        let (x, y) = match along(3) {
+            0 => (along(max_x), 0),
+            1 => (along(max_x), max_y),
+            2 => (0, along(max_y)),
+            _ => (max_x, along(max_y)),
+        };
+
+        let mut glider = LifeGame::new(GLIDER_SIZE, GLIDER_SIZE);
+        glider.set_alives(&GLIDER);
+        if x * 2 > max_x {
+            glider.flip_horizontal();
+        }
+        if y * 2 > max_y {
+            glider.flip_vertical();
+        }
+        self.stamp(&glider, x, y);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::LifeGame;
+
+impl LifeGame {
+    /// Renders the board as RGBA pixels, row by row, drawing each cell as a
+    /// `cell_size` x `cell_size` square.
+    pub fn render_rgba(&self, cell_size: usize, live: [u8; 4], dead: [u8; 4]) -> Vec<u8> {
+        let row_len = self.width * cell_size * 4;
+        let mut pixels = Vec::with_capacity(row_len * self.height * cell_size);
+
+        for row in self.cells_iter() {
+            let line: Vec<u8> = row
+                .flat_map(|alive| {
+                    let color = if alive { live } else { dead };
+                    color.repeat(cell_size)
+                })
+                .collect();
+            for _ in 0..cell_size {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        pixels
+    }
+
+    /// Renders the board with the `Display` glyphs, a column ruler across the
+    /// top and row indices down the left. Ruler digits wrap modulo 10 so they
+    /// stay aligned on wide boards.
+    pub fn render_debug(&self) -> String {
+        let gutter = self.height.saturating_sub(1).to_string().len();
+        let ruler: String = (0..self.width)
+            .map(|x| char::from(b'0' + (x % 10) as u8))
+            .collect();
+
+        let mut out = format!("{:gutter$} {}\n", "", ruler);
+        for (y, row) in self.cells_iter().enumerate() {
+            let line: String = row.map(|alive| if alive { '+' } else { '.' }).collect();
+            out += &format!("{:>gutter$} {}\n", y, line);
+        }
+        out
+    }
+}
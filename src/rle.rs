@@ -0,0 +1,396 @@
+use std::io::BufRead;
+
+use crate::{check_size, LifeGame, PatternError, Rule, LIVE, MAX_CELLS};
+
+struct RleParser {
+    game: LifeGame,
+    x: usize,
+    y: usize,
+    count: usize,
+    ended: bool,
+}
+
+impl RleParser {
+    fn new(name: &str, header: &str, max_cells: usize) -> Result<Self, PatternError> {
+        let mut width = None;
+        let mut height = None;
+        let mut rule = Rule::default();
+
+        for field in header.split(',') {
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| PatternError::InvalidHeader(header.into()))?;
+            let value = value.trim();
+            match key.trim() {
+                "x" => width = value.parse::<usize>().ok(),
+                "y" => height = value.parse::<usize>().ok(),
+                "rule" => rule = Rule::parse(value)?,
+                _ => {}
+            }
+        }
+
+        let (Some(width), Some(height)) = (width, height) else {
+            return Err(PatternError::InvalidHeader(header.into()));
+        };
+
+        check_size(width, height, max_cells)?;
+
+        let mut game = LifeGame::new(width, height);
+        game.name = name.into();
+        game.rule = rule;
+
+        Ok(RleParser {
+            game,
+            x: 0,
+            y: 0,
+            count: 0,
+            ended: false,
+        })
+    }
+
+    fn feed(&mut self, line: usize, text: &str) -> Result<(), PatternError> {
+        for (col, token) in text.chars().enumerate() {
+            if self.ended {
+                break;
+            }
+
+            let col = col + 1;
+            let count = if self.count == 0 { 1 } else { self.count };
+
+            match token {
+                '0'..='9' => {
+                    let digit = token.to_digit(10).unwrap() as usize;
+                    self.count = self
+                        .count
+                        .checked_mul(10)
+                        .and_then(|count| count.checked_add(digit))
+                        .ok_or_else(|| self.overrun(usize::MAX, self.y))?;
+                    continue;
+                }
+                'b' | 'o' => {
+                    let end = self
+                        .x
+                        .checked_add(count)
+                        .filter(|&end| self.y < self.game.height && end <= self.game.width)
+                        .ok_or_else(|| self.overrun(self.x.saturating_add(count), self.y))?;
+                    if token == 'o' {
+                        for x in self.x..end {
+                            self.game.cells[self.y][x] = LIVE;
+                        }
+                    }
+                    self.x = end;
+                }
+                '$' => {
+                    self.y = self
+                        .y
+                        .checked_add(count)
+                        .ok_or_else(|| self.overrun(self.x, usize::MAX))?;
+                    self.x = 0;
+                }
+                '!' => self.ended = true,
+                token if token.is_whitespace() => {}
+                token => return Err(PatternError::UnexpectedToken { line, col, token }),
+            }
+
+            self.count = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Reports a run reaching `x` on row `y`, past the declared size.
+    fn overrun(&self, x: usize, y: usize) -> PatternError {
+        PatternError::DimensionMismatch {
+            expected: (self.game.width, self.game.height),
+            actual: (x, y.saturating_add(1)),
+        }
+    }
+}
+
+const LINE_WIDTH: usize = 70;
+
+fn is_header(line: &str) -> bool {
+    line.trim_start().starts_with('x')
+}
+
+fn is_comment(line: &str) -> bool {
+    line.trim_start().starts_with('#')
+}
+
+fn pattern_name(lines: &[&str]) -> Option<String> {
+    lines
+        .iter()
+        .filter_map(|line| line.trim_start().strip_prefix("#N"))
+        .map(|name| name.trim().to_string())
+        .find(|name| !name.is_empty())
+}
+
+fn parse_lines<S, I>(name: &str, lines: I, max_cells: usize) -> Result<LifeGame, PatternError>
+where
+    S: AsRef<str>,
+    I: Iterator<Item = Result<S, PatternError>>,
+{
+    let mut parser: Option<RleParser> = None;
+
+    for (index, line) in lines.enumerate() {
+        let line = line?;
+        let line = line.as_ref();
+        if line.trim().is_empty() || is_comment(line) {
+            continue;
+        }
+        match parser.as_mut() {
+            Some(parser) => parser.feed(index + 1, line)?,
+            None => parser = Some(RleParser::new(name, line, max_cells)?),
+        }
+    }
+
+    parser.map(|parser| parser.game).ok_or(PatternError::Empty)
+}
+
+impl LifeGame {
+    pub fn from_rle(name: &str, rle: &str) -> Result<LifeGame, PatternError> {
+        LifeGame::from_rle_with_limit(name, rle, MAX_CELLS)
+    }
+
+    /// Same as `from_rle`, but rejects any header declaring more than
+    /// `max_cells` cells before the board is allocated.
+    pub fn from_rle_with_limit(
+        name: &str,
+        rle: &str,
+        max_cells: usize,
+    ) -> Result<LifeGame, PatternError> {
+        parse_lines(name, rle.lines().map(Ok), max_cells)
+    }
+
+    /// Same as `from_rle`, but reads the pattern line by line from `reader`
+    /// instead of requiring the whole text in memory. Run counts split across
+    /// lines carry over exactly as in the string parser.
+    pub fn from_rle_reader<R: BufRead>(name: &str, reader: R) -> Result<LifeGame, PatternError> {
+        LifeGame::from_rle_reader_with_limit(name, reader, MAX_CELLS)
+    }
+
+    /// Same as `from_rle_reader`, with the cell limit of `from_rle_with_limit`.
+    pub fn from_rle_reader_with_limit<R: BufRead>(
+        name: &str,
+        reader: R,
+        max_cells: usize,
+    ) -> Result<LifeGame, PatternError> {
+        parse_lines(
+            name,
+            reader.lines().map(|line| line.map_err(PatternError::from)),
+            max_cells,
+        )
+    }
+
+    /// Encodes the board as RLE with an `x`, `y` and `rule` header. Dead cells
+    /// at the end of a row and empty rows at the bottom are left out, so an
+    /// all-dead board has a body of just `!`. Body lines wrap at 70 columns.
+    pub fn to_rle(&self) -> String {
+        let mut runs: Vec<(usize, char)> = Vec::new();
+        let mut push = |count: usize, token: char| match runs.last_mut() {
+            Some((run, last)) if *last == token => *run += count,
+            _ => runs.push((count, token)),
+        };
+
+        let mut pending_rows = 0;
+        for y in 0..self.height {
+            let row = self.row_runs(y).unwrap_or_default();
+            if row.is_empty() {
+                pending_rows += 1;
+                continue;
+            }
+            if pending_rows > 0 {
+                push(pending_rows, '$');
+            }
+            let mut x = 0;
+            for (start, length) in row {
+                if start > x {
+                    push(start - x, 'b');
+                }
+                push(length, 'o');
+                x = start + length;
+            }
+            pending_rows = 1;
+        }
+
+        let mut rle = format!(
+            "x = {}, y = {}, rule = {}\n",
+            self.width, self.height, self.rule
+        );
+        let mut line = String::new();
+        for (count, token) in runs {
+            let item = match count {
+                1 => token.to_string(),
+                count => format!("{}{}", count, token),
+            };
+            if line.len() + item.len() > LINE_WIDTH {
+                rle += &line;
+                rle.push('\n');
+                line.clear();
+            }
+            line += &item;
+        }
+        if line.len() >= LINE_WIDTH {
+            rle += &line;
+            rle.push('\n');
+            line.clear();
+        }
+        rle += &line;
+        rle += "!\n";
+        rle
+    }
+
+    /// Parses an RLE pattern and places it at `(off_x, off_y)` on an otherwise
+    /// dead `board_w` x `board_h` board, clipping what falls outside.
+    pub fn from_rle_at(
+        name: &str,
+        rle: &str,
+        board_w: usize,
+        board_h: usize,
+        off_x: usize,
+        off_y: usize,
+    ) -> Result<LifeGame, PatternError> {
+        LifeGame::from_rle(name, rle)?.placed(board_w, board_h, off_x, off_y)
+    }
+
+    /// Parses every pattern in a collection file. Each `x =` header starts a
+    /// new pattern, and the `#` lines written after the previous `!` belong to
+    /// it. Patterns are named from their `#N` line, or `PATTERN <n>` otherwise.
+    pub fn many_from_rle(rle: &str) -> Result<Vec<LifeGame>, PatternError> {
+        let mut sections: Vec<Vec<&str>> = Vec::new();
+        let mut current: Vec<&str> = Vec::new();
+        let mut pending: Vec<&str> = Vec::new();
+        let mut has_header = false;
+        let mut ended = false;
+
+        for line in rle.lines() {
+            if is_header(line) {
+                if has_header {
+                    sections.push(std::mem::take(&mut current));
+                }
+                current.append(&mut pending);
+                current.push(line);
+                has_header = true;
+                ended = false;
+            } else if ended && is_comment(line) {
+                pending.push(line);
+            } else {
+                ended |= has_header && line.contains('!');
+                current.push(line);
+            }
+        }
+
+        if has_header {
+            sections.push(current);
+        }
+
+        sections
+            .iter()
+            .enumerate()
+            .map(|(index, lines)| {
+                let name = pattern_name(lines).unwrap_or_else(|| format!("PATTERN {}", index + 1));
+                LifeGame::from_rle(&name, &lines.join("\n")).map_err(|error| {
+                    PatternError::Pattern {
+                        index,
+                        error: Box::new(error),
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_absurd_header() {
+        let error = LifeGame::from_rle("", "x = 100000, y = 100000\n!").unwrap_err();
+        assert!(matches!(error, PatternError::TooLarge { .. }));
+
+        let error = LifeGame::from_rle("", "x = 18446744073709551615, y = 2\n!").unwrap_err();
+        assert!(matches!(error, PatternError::TooLarge { .. }));
+    }
+
+    #[test]
+    fn applies_limit_to_reader() {
+        let rle = "x = 4, y = 4\n4o!";
+        let error = LifeGame::from_rle_reader_with_limit("", rle.as_bytes(), 15).unwrap_err();
+        assert!(matches!(
+            error,
+            PatternError::TooLarge { max_cells: 15, .. }
+        ));
+        assert!(LifeGame::from_rle_reader_with_limit("", rle.as_bytes(), 16).is_ok());
+    }
+
+    #[test]
+    fn rejects_overflowing_counts() {
+        for body in [
+            "99999999999999999999o!",
+            "o18446744073709551615o!",
+            "18446744073709551615$o!",
+        ] {
+            let error = LifeGame::from_rle("", &format!("x = 3, y = 1\n{}", body)).unwrap_err();
+            assert!(
+                matches!(error, PatternError::DimensionMismatch { .. }),
+                "{}: {:?}",
+                body,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_run_past_width() {
+        let error = LifeGame::from_rle("", "x = 3, y = 1\n4o!").unwrap_err();
+        assert!(matches!(
+            error,
+            PatternError::DimensionMismatch {
+                expected: (3, 1),
+                actual: (4, 1),
+            }
+        ));
+    }
+
+    #[test]
+    fn names_failed_pattern_from_one() {
+        let rle = "x = 1, y = 1\no!\nx = 1, y = 1\n2o!";
+        let error = LifeGame::many_from_rle(rle).unwrap_err();
+        assert!(matches!(error, PatternError::Pattern { index: 1, .. }));
+        assert!(error.to_string().starts_with("pattern 2: "));
+    }
+
+    #[test]
+    fn rejects_side_longer_than_u16() {
+        let error = LifeGame::from_rle("", "x = 70000, y = 1\n69999bo!").unwrap_err();
+        assert!(matches!(
+            error,
+            PatternError::TooLarge {
+                width: 70000,
+                height: 1,
+                ..
+            }
+        ));
+        assert!(LifeGame::from_rle("", "x = 65535, y = 1\n65534bo!").is_ok());
+    }
+
+    #[test]
+    fn reader_matches_string_across_split_lines() {
+        for rle in [
+            "x = 12, y = 1\n1\n1o!",
+            "x = 12, y = 3\n2b3\no$\n1\n2o\n$b\no!",
+        ] {
+            let from_str = LifeGame::from_rle("", rle).unwrap();
+            let from_reader = LifeGame::from_rle_reader("", rle.as_bytes()).unwrap();
+            assert!(from_str.same_cells(&from_reader), "{:?}", rle);
+
+            let reader = std::io::BufReader::with_capacity(1, rle.as_bytes());
+            let from_tiny_buffer = LifeGame::from_rle_reader("", reader).unwrap();
+            assert!(from_str.same_cells(&from_tiny_buffer), "{:?}", rle);
+        }
+
+        let game = LifeGame::from_rle_reader("", "x = 12, y = 1\n1\n1o!".as_bytes()).unwrap();
+        assert_eq!(game.population(), 11);
+    }
+}
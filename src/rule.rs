@@ -0,0 +1,109 @@
+use std::{error::Error, fmt::Display};
+
+pub trait Transition: Send + Sync {
+    fn next(&self, current: bool, live_neighbors: usize) -> bool;
+}
+
+impl<F> Transition for F
+where
+    F: Fn(bool, usize) -> bool + Send + Sync,
+{
+    fn next(&self, current: bool, live_neighbors: usize) -> bool {
+        self(current, live_neighbors)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    birth: [bool; 9],
+    survive: [bool; 9],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleParseError(String);
+
+impl Display for RuleParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid rule: {}", self.0)
+    }
+}
+
+impl Error for RuleParseError {}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = |counts: &[bool; 9]| -> String {
+            (0..9)
+                .filter(|&count| counts[count])
+                .map(|count| count.to_string())
+                .collect()
+        };
+        write!(f, "B{}/S{}", digits(&self.birth), digits(&self.survive))
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::new(&[3], &[2, 3])
+    }
+}
+
+impl Rule {
+    /// B2/S34, the usual rule for the hexagonal neighbourhood.
+    pub fn hex() -> Self {
+        Rule::new(&[2], &[3, 4])
+    }
+
+    pub fn new(birth: &[usize], survive: &[usize]) -> Self {
+        let mut rule = Rule {
+            birth: [false; 9],
+            survive: [false; 9],
+        };
+        for &count in birth.iter().filter(|&&count| count <= 8) {
+            rule.birth[count] = true;
+        }
+        for &count in survive.iter().filter(|&&count| count <= 8) {
+            rule.survive[count] = true;
+        }
+        rule
+    }
+
+    /// Parses `B3/S23` notation, or the older `23/3` survive/birth notation.
+    pub fn parse(text: &str) -> Result<Rule, RuleParseError> {
+        let error = || RuleParseError(text.into());
+        let (first, second) = text.trim().split_once('/').ok_or_else(error)?;
+
+        let (birth, survive) = match (strip_letter(first, 'b'), strip_letter(second, 's')) {
+            (Some(birth), Some(survive)) => (birth, survive),
+            (None, None) => (second, first),
+            _ => return Err(error()),
+        };
+
+        Ok(Rule {
+            birth: parse_counts(birth).ok_or_else(error)?,
+            survive: parse_counts(survive).ok_or_else(error)?,
+        })
+    }
+}
+
+impl Transition for Rule {
+    fn next(&self, current: bool, live_neighbors: usize) -> bool {
+        let counts = if current { &self.survive } else { &self.birth };
+        counts.get(live_neighbors).copied().unwrap_or(false)
+    }
+}
+
+fn strip_letter(text: &str, letter: char) -> Option<&str> {
+    let text = text.trim();
+    text.strip_prefix(letter)
+        .or_else(|| text.strip_prefix(letter.to_ascii_uppercase()))
+}
+
+fn parse_counts(text: &str) -> Option<[bool; 9]> {
+    let mut counts = [false; 9];
+    for c in text.chars() {
+        let count = c.to_digit(10).filter(|&count| count <= 8)?;
+        counts[count as usize] = true;
+    }
+    Some(counts)
+}
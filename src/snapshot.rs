@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use crate::{Cells, LifeGame, LIVE};
+
+/// An immutable copy of a board that can be shared with other threads while
+/// the original keeps stepping.
+#[derive(Debug, Clone)]
+pub struct BoardSnapshot {
+    cells: Arc<Cells>,
+    width: usize,
+    height: usize,
+    generation: usize,
+}
+
+impl BoardSnapshot {
+    pub fn cells_iter(&self) -> impl Iterator<Item = impl Iterator<Item = bool> + '_> + '_ {
+        self.cells.iter().map(|row| row.iter().map(|&c| c == LIVE))
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width as u16
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height as u16
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+}
+
+impl LifeGame {
+    pub fn snapshot(&self) -> BoardSnapshot {
+        BoardSnapshot {
+            cells: Arc::new(self.cells.clone()),
+            width: self.width,
+            height: self.height,
+            generation: self.generation,
+        }
+    }
+}
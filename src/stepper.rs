@@ -0,0 +1,37 @@
+use crate::LifeGame;
+
+/// An iterator over successive generations of a board, created by
+/// `LifeGame::into_stepper`.
+///
+/// Each call to `next` advances the board with `LifeGame::next` and yields a
+/// copy of the new generation, without its step history. The iterator ends as
+/// soon as `LifeGame::next` reports the board is stable, so the starting board
+/// itself is never yielded.
+pub struct Stepper {
+    game: LifeGame,
+}
+
+impl Iterator for Stepper {
+    type Item = LifeGame;
+
+    fn next(&mut self) -> Option<LifeGame> {
+        self.game.next()?;
+        Some(self.game.detached())
+    }
+}
+
+impl LifeGame {
+    pub fn into_stepper(self) -> Stepper {
+        Stepper { game: self }
+    }
+
+    /// Returns up to `count` successive generations starting with this one,
+    /// stopping early once the board is stable. Each frame is an independent
+    /// copy without step history.
+    pub fn collect_frames(&self, count: usize) -> Vec<LifeGame> {
+        std::iter::once(self.detached())
+            .chain(self.detached().into_stepper())
+            .take(count)
+            .collect()
+    }
+}
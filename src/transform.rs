@@ -0,0 +1,113 @@
+use crate::LifeGame;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    /// Mirrored across the vertical center line.
+    Horizontal,
+    /// Mirrored across the horizontal center line.
+    Vertical,
+    /// Mirrored across both center lines.
+    FourFold,
+}
+
+impl LifeGame {
+    pub fn flip_horizontal(&mut self) {
+        for row in self.cells.iter_mut() {
+            row.reverse();
+        }
+    }
+
+    pub fn flip_vertical(&mut self) {
+        self.cells.reverse();
+    }
+
+    /// Returns the smallest `(x, y, width, height)` rectangle containing every
+    /// live cell, or `None` when the board is all dead.
+    pub fn live_bounds(&self) -> Option<(usize, usize, usize, usize)> {
+        let mut cells = self.live_cells();
+        let (x, y) = cells.next()?;
+        let (left, top, right, bottom) = cells.fold((x, y, x, y), |(l, t, r, b), (x, y)| {
+            (l.min(x), t.min(y), r.max(x), b.max(y))
+        });
+        Some((left, top, right - left + 1, bottom - top + 1))
+    }
+
+    /// Returns `live_bounds` grown by `margin` cells on every side and clamped
+    /// to the board, or `None` when the board is all dead.
+    pub fn framed_bounds(&self, margin: usize) -> Option<(usize, usize, usize, usize)> {
+        let (x, y, width, height) = self.live_bounds()?;
+        let left = x.saturating_sub(margin);
+        let top = y.saturating_sub(margin);
+        let right = (x + width).saturating_add(margin).min(self.width);
+        let bottom = (y + height).saturating_add(margin).min(self.height);
+        Some((left, top, right - left, bottom - top))
+    }
+
+    /// Returns a copy of the `width` x `height` region at `(x, y)`, clipped to
+    /// the board.
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> LifeGame {
+        let right = x.saturating_add(width).min(self.width);
+        let bottom = y.saturating_add(height).min(self.height);
+        let cells = self
+            .cells
+            .iter()
+            .take(bottom)
+            .skip(y)
+            .map(|row| row[x.min(right)..right].to_vec())
+            .collect();
+        self.with_cells(cells)
+    }
+
+    /// Shrinks the board in place to `live_bounds`. An all-dead board trims
+    /// to 0x0.
+    pub fn trim(&mut self) {
+        let (x, y, width, height) = self.live_bounds().unwrap_or_default();
+        *self = self.crop(x, y, width, height);
+    }
+
+    /// Copies the left and/or top half of the board onto the other half(s)
+    /// so that it has the requested symmetry. With `FourFold` the top-left
+    /// quadrant ends up in all four corners. On odd dimensions the center
+    /// row or column is shared and kept as is.
+    pub fn make_symmetric(&mut self, sym: Symmetry) {
+        if matches!(sym, Symmetry::Horizontal | Symmetry::FourFold) {
+            for row in self.cells.iter_mut() {
+                for x in self.width - self.width / 2..self.width {
+                    row[x] = row[self.width - 1 - x];
+                }
+            }
+        }
+        if matches!(sym, Symmetry::Vertical | Symmetry::FourFold) {
+            for y in self.height - self.height / 2..self.height {
+                self.cells[y] = self.cells[self.height - 1 - y].clone();
+            }
+        }
+    }
+
+    pub fn is_symmetric(&self, sym: Symmetry) -> bool {
+        let horizontal = || self.cells.iter().all(|row| row.iter().eq(row.iter().rev()));
+        let vertical = || self.cells.iter().eq(self.cells.iter().rev());
+        match sym {
+            Symmetry::Horizontal => horizontal(),
+            Symmetry::Vertical => vertical(),
+            Symmetry::FourFold => horizontal() && vertical(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn make_symmetric_passes_is_symmetric() {
+        for (width, height) in [(4, 4), (5, 3), (6, 7), (3, 1)] {
+            for sym in [Symmetry::Horizontal, Symmetry::Vertical, Symmetry::FourFold] {
+                let mut game = LifeGame::new(width, height);
+                game.set_alives(&[(0, 0), (1, height - 1), (width - 1, height / 2)]);
+                game.make_symmetric(sym);
+                assert!(game.is_symmetric(sym), "{}x{} {:?}", width, height, sym);
+            }
+        }
+    }
+}